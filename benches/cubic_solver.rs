@@ -0,0 +1,61 @@
+use bevy_lookup_curve::*;
+use bevy_math::Vec2;
+use criterion::BenchmarkId;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Weighted cubic segment with extreme weight asymmetry
+fn asymmetric_curve(solver: WeightedCubicSolver) -> LookupCurve {
+    LookupCurve::with_knots(vec![
+        Knot {
+            position: Vec2::ZERO,
+            interpolation: KnotInterpolation::Cubic,
+            right_tangent: Tangent {
+                slope: 4.0,
+                weight: Some(0.95),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        Knot {
+            position: Vec2::ONE,
+            left_tangent: Tangent {
+                slope: 0.0,
+                weight: Some(0.02),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    ])
+    .unwrap()
+    .with_cubic_solver(solver)
+}
+
+pub fn cubic_solver(c: &mut Criterion) {
+    let solvers = [
+        (
+            "NewtonRaphsonFromX",
+            WeightedCubicSolver::NewtonRaphsonFromX,
+        ),
+        (
+            "NewtonRaphsonFromMidpoint",
+            WeightedCubicSolver::NewtonRaphsonFromMidpoint,
+        ),
+        ("Bisection", WeightedCubicSolver::Bisection),
+    ];
+
+    let mut group = c.benchmark_group("Weighted cubic solver (asymmetric weights)");
+    for (name, solver) in solvers {
+        let curve = asymmetric_curve(solver);
+        group.bench_with_input(BenchmarkId::new(name, 1000), &curve, |b, curve| {
+            b.iter(|| {
+                (0..1000).map(|i| i as f32 / 1000.0).for_each(|x| {
+                    curve.lookup(black_box(x));
+                })
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, cubic_solver);
+criterion_main!(benches);
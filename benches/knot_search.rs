@@ -1,5 +1,5 @@
 use bevy_lookup_curve::knot_search::KnotSearch;
-use bevy_lookup_curve::Knot;
+use bevy_lookup_curve::{Knot, LookupCurve};
 use bevy_math::Vec2;
 use criterion::BenchmarkId;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
@@ -61,5 +61,34 @@ pub fn knot_search(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches2, knot_search);
+pub fn uniform_lookup_table(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Uniform lookup table (sweep)");
+    let sweep_samples: Vec<f32> = (1..999).map(|i| i as f32 / 1000.).collect();
+    for i in [16, 256, 4096].iter() {
+        // Baked linear curve, searched using binary search
+        let xs: Vec<f32> = (0..*i).map(|j| j as f32 / (*i - 1) as f32).collect();
+        let curve = LookupCurve::with_knots(generate_knots(2))
+            .unwrap()
+            .resample_at(&xs);
+        let table = curve.bake_uniform(*i);
+
+        group.bench_with_input(BenchmarkId::new("Linear curve", i), i, |b, _| {
+            b.iter(|| {
+                sweep_samples.iter().for_each(|x| {
+                    curve.lookup(black_box(*x));
+                })
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("Uniform table", i), i, |b, _| {
+            b.iter(|| {
+                sweep_samples.iter().for_each(|x| {
+                    table.lookup(black_box(*x));
+                })
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches2, knot_search, uniform_lookup_table);
 criterion_main!(benches2);
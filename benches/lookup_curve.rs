@@ -1,9 +1,21 @@
 use bevy_lookup_curve::*;
 use bevy_math::Vec2;
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+fn generate_cubic_knots(n: usize) -> Vec<Knot> {
+    let step = 1. / (n - 1) as f32;
+    (0..n)
+        .map(|i| Knot {
+            position: Vec2::new(step * i as f32, (i % 2) as f32),
+            interpolation: KnotInterpolation::Cubic,
+            ..Default::default()
+        })
+        .collect()
+}
 
 pub fn linear(c: &mut Criterion) {
-    let curve = LookupCurve::new(vec![
+    let curve = LookupCurve::with_knots(vec![
         Knot {
             position: Vec2::ZERO,
             interpolation: KnotInterpolation::Linear,
@@ -14,7 +26,8 @@ pub fn linear(c: &mut Criterion) {
             interpolation: KnotInterpolation::Linear,
             ..Default::default()
         },
-    ]);
+    ])
+    .unwrap();
     c.bench_function("0_1_linear_1000", |b| {
         b.iter(|| {
             (0..1000).map(|i| i as f32 / 1000.0).for_each(|x| {
@@ -25,7 +38,7 @@ pub fn linear(c: &mut Criterion) {
 }
 
 pub fn unweighted_cubic(c: &mut Criterion) {
-    let curve = LookupCurve::new(vec![
+    let curve = LookupCurve::with_knots(vec![
         Knot {
             position: Vec2::ZERO,
             interpolation: KnotInterpolation::Cubic,
@@ -36,7 +49,8 @@ pub fn unweighted_cubic(c: &mut Criterion) {
             interpolation: KnotInterpolation::Cubic,
             ..Default::default()
         },
-    ]);
+    ])
+    .unwrap();
     c.bench_function("0_1_unweighted_cubic_1000", |b| {
         b.iter(|| {
             (0..1000).map(|i| i as f32 / 1000.0).for_each(|x| {
@@ -47,7 +61,7 @@ pub fn unweighted_cubic(c: &mut Criterion) {
 }
 
 pub fn weighted_cubic(c: &mut Criterion) {
-    let curve = LookupCurve::new(vec![
+    let curve = LookupCurve::with_knots(vec![
         Knot {
             position: Vec2::ZERO,
             interpolation: KnotInterpolation::Cubic,
@@ -65,7 +79,8 @@ pub fn weighted_cubic(c: &mut Criterion) {
             },
             ..Default::default()
         },
-    ]);
+    ])
+    .unwrap();
     c.bench_function("0_1_weighted_cubic_1000", |b| {
         b.iter(|| {
             (0..1000).map(|i| i as f32 / 1000.0).for_each(|x| {
@@ -75,5 +90,152 @@ pub fn weighted_cubic(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, linear, unweighted_cubic, weighted_cubic,);
+/// Curve from (0, 0) to (1, 1) with the given slopes. Weights of 1/3 give the same control points as an unweighted segment.
+fn slope_curve(slope_a: f32, slope_b: f32, weight: Option<f32>) -> LookupCurve {
+    LookupCurve::with_knots(vec![
+        Knot {
+            position: Vec2::ZERO,
+            interpolation: KnotInterpolation::Cubic,
+            right_tangent: Tangent {
+                slope: slope_a,
+                weight,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        Knot {
+            position: Vec2::ONE,
+            left_tangent: Tangent {
+                slope: slope_b,
+                weight,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    ])
+    .unwrap()
+}
+
+pub fn weighted_vs_unweighted(c: &mut Criterion) {
+    let mut group = c.benchmark_group("weighted_vs_unweighted");
+    for (name, weight) in [("unweighted", None), ("weighted", Some(1.0 / 3.0))] {
+        let curve = slope_curve(2.0, 0.5, weight);
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                (0..1000).map(|i| i as f32 / 1000.0).for_each(|x| {
+                    curve.lookup(black_box(x));
+                })
+            })
+        });
+    }
+    group.finish();
+}
+
+pub fn weighted_max_iters(c: &mut Criterion) {
+    // Heavy weights and steep slopes give a segment with high curvature, where Newton-Raphson converges slowly
+    let curve = slope_curve(20.0, -5.0, Some(0.95));
+
+    let mut group = c.benchmark_group("weighted_max_iters");
+    for max_iters in [1, 2, 5, 10, 20, 50] {
+        let curve = curve.clone().with_max_iters(max_iters).with_max_error(1e-7);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(max_iters),
+            &max_iters,
+            |b, _| {
+                b.iter(|| {
+                    (0..1000).map(|i| i as f32 / 1000.0).for_each(|x| {
+                        curve.lookup(black_box(x));
+                    })
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+pub fn lookup_cached(c: &mut Criterion) {
+    let curve_sizes = [2, 5, 10, 25, 50, 100];
+
+    let sweep_samples: Vec<f32> = (1..999).map(|i| i as f32 / 1000.).collect();
+    let mut random_samples = sweep_samples.clone();
+    random_samples.shuffle(&mut StdRng::seed_from_u64(42));
+
+    let mut group = c.benchmark_group("Lookup cached");
+    for i in curve_sizes.iter() {
+        let curve = LookupCurve::with_knots(generate_cubic_knots(*i)).unwrap();
+        for (access, samples) in [("sweep", &sweep_samples), ("random", &random_samples)] {
+            group.bench_with_input(
+                BenchmarkId::new(format!("Uncached ({access})"), i),
+                i,
+                |b, _| {
+                    b.iter(|| {
+                        samples.iter().for_each(|x| {
+                            curve.lookup(black_box(*x));
+                        })
+                    })
+                },
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("Cached ({access})"), i),
+                i,
+                |b, _| {
+                    b.iter(|| {
+                        let mut cache = LookupCache::new();
+                        samples.iter().for_each(|x| {
+                            curve.lookup_cached(black_box(*x), &mut cache);
+                        })
+                    })
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+/// Alternates moving a middle knot with coherent cached lookups, to catch a cache that keeps missing after the curve is modified
+pub fn lookup_cached_after_modify(c: &mut Criterion) {
+    let curve_sizes = [5, 25, 100];
+    let samples: Vec<f32> = (0..100).map(|i| 0.3 + i as f32 / 1000.).collect();
+
+    let mut group = c.benchmark_group("Lookup cached after modify");
+    for i in curve_sizes.iter() {
+        let mut curve = LookupCurve::with_knots(generate_cubic_knots(*i)).unwrap();
+        let mut cache = LookupCache::new();
+        group.bench_with_input(BenchmarkId::new("Unmodified", i), i, |b, _| {
+            b.iter(|| {
+                samples.iter().for_each(|x| {
+                    curve.lookup_cached(black_box(*x), &mut cache);
+                })
+            })
+        });
+
+        let middle = *i / 2;
+        let mut epsilon = 1e-5;
+        group.bench_with_input(BenchmarkId::new("Modified", i), i, |b, _| {
+            b.iter(|| {
+                // Move the knot back and forth to keep the curve stable across iterations
+                let mut knot = curve.knots()[middle].clone();
+                knot.position.x += epsilon;
+                epsilon = -epsilon;
+                curve.modify_knot(middle, knot);
+
+                samples.iter().for_each(|x| {
+                    curve.lookup_cached(black_box(*x), &mut cache);
+                })
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    linear,
+    unweighted_cubic,
+    weighted_cubic,
+    weighted_vs_unweighted,
+    weighted_max_iters,
+    lookup_cached,
+    lookup_cached_after_modify
+);
 criterion_main!(benches);
@@ -0,0 +1,31 @@
+use bevy_lookup_curve::*;
+use bevy_math::Vec2;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+pub fn batch_vs_parallel(c: &mut Criterion) {
+    let knots = (0..50)
+        .map(|i| Knot {
+            position: Vec2::new(i as f32 / 49.0, (i % 2) as f32),
+            interpolation: KnotInterpolation::Cubic,
+            ..Default::default()
+        })
+        .collect();
+    let curve = LookupCurve::with_knots(knots).unwrap();
+
+    // Varying the batch size shows where the parallel overhead pays off
+    let mut group = c.benchmark_group("batch_vs_parallel");
+    for len in [100, 1000, 10000, 100000] {
+        let xs: Vec<f32> = (0..len).map(|i| i as f32 / len as f32).collect();
+        let mut out = vec![0.0; len];
+        group.bench_with_input(BenchmarkId::new("batch", len), &len, |b, _| {
+            b.iter(|| curve.lookup_batch(black_box(&xs), &mut out))
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", len), &len, |b, _| {
+            b.iter(|| curve.lookup_parallel(black_box(&xs), &mut out))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, batch_vs_parallel);
+criterion_main!(benches);
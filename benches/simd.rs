@@ -0,0 +1,47 @@
+use bevy_lookup_curve::*;
+use bevy_math::Vec2;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn generate_knots(n: usize, interpolation: KnotInterpolation) -> Vec<Knot> {
+    let step = 1. / (n - 1) as f32;
+    (0..n)
+        .map(|i| Knot {
+            position: Vec2::new(step * i as f32, (i % 2) as f32),
+            interpolation,
+            ..Default::default()
+        })
+        .collect()
+}
+
+pub fn scalar_vs_simd4(c: &mut Criterion) {
+    let xs: Vec<[f32; 4]> = (0..250)
+        .map(|i| [0.0, 0.25, 0.5, 0.75].map(|offset| offset + i as f32 / 1000.0))
+        .collect();
+
+    for (name, interpolation) in [
+        ("linear", KnotInterpolation::Linear),
+        ("unweighted_cubic", KnotInterpolation::Cubic),
+    ] {
+        let curve = LookupCurve::with_knots(generate_knots(10, interpolation)).unwrap();
+
+        let mut group = c.benchmark_group(format!("scalar_vs_simd4 ({name})"));
+        group.bench_function("scalar", |b| {
+            b.iter(|| {
+                xs.iter().for_each(|xs| {
+                    black_box(xs.map(|x| curve.lookup(black_box(x))));
+                })
+            })
+        });
+        group.bench_function("simd4", |b| {
+            b.iter(|| {
+                xs.iter().for_each(|xs| {
+                    black_box(curve.lookup_simd4(black_box(*xs)));
+                })
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, scalar_vs_simd4);
+criterion_main!(benches);
@@ -45,18 +45,11 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
             speed: 0.3,
         },
         AnimateWithCurve(
-            LookupCurve::new(vec![
-                Knot {
-                    position: Vec2::ZERO,
-                    interpolation: KnotInterpolation::Linear,
-                    ..Default::default()
-                },
-                Knot {
-                    position: Vec2::ONE,
-                    interpolation: KnotInterpolation::Linear,
-                    ..default()
-                },
+            LookupCurve::with_knots(vec![
+                Knot::at(0.0, 0.0).with_interpolation(KnotInterpolation::Linear),
+                Knot::at(1.0, 1.0).with_interpolation(KnotInterpolation::Linear),
             ])
+            .unwrap()
             .with_name("Animation curve"),
         ),
         AnimationCache(LookupCache::new()),
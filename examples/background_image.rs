@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPlugin};
+
+use bevy_lookup_curve::{editor::LookupCurveEguiEditor, Knot, KnotInterpolation, LookupCurve};
+
+/// This example paints an image behind the curve in the editor, e.g. to use sprite animation frames as a reference
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(EguiPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, update)
+        .run();
+}
+
+#[derive(Component)]
+struct EditorWindow {
+    curve: LookupCurve,
+    editor: LookupCurveEguiEditor,
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>, mut contexts: EguiContexts) {
+    commands.spawn(Camera2d);
+
+    // Register the image with egui to get a texture id that the editor can paint
+    let texture = contexts.add_image(asset_server.load("bevy_icon.png"));
+
+    commands.spawn(EditorWindow {
+        curve: LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.0).with_interpolation(KnotInterpolation::Cubic),
+            Knot::at(1.0, 1.0),
+        ])
+        .unwrap()
+        .with_name("Background image"),
+        // Stretch the image over the unit square in curve space
+        editor: LookupCurveEguiEditor::default().with_background_image(
+            texture,
+            Vec2::ZERO,
+            Vec2::ONE,
+        ),
+    });
+}
+
+fn update(mut windows: Query<(Entity, &mut EditorWindow)>, mut contexts: EguiContexts) {
+    for (entity, mut window) in windows.iter_mut() {
+        let EditorWindow { curve, editor } = &mut *window;
+        editor.ui_window(contexts.ctx_mut(), entity, curve, None);
+    }
+}
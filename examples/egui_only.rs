@@ -11,13 +11,17 @@ fn main() -> eframe::Result {
     let path = "./assets/example.curve.ron";
     let lookup_curve = LookupCurve::load_from_file(path).expect("Failed to load curve");
 
+    // Reload the curve when the file is changed on disk
+    let mut editor = LookupCurveEguiEditor::with_save_path(path.to_string());
+    editor.enable_file_watch();
+
     eframe::run_native(
         "Lookup Curve (egui only example)",
         options,
         Box::new(|_| {
             Ok(Box::new(MyApp {
                 lookup_curve,
-                editor: LookupCurveEguiEditor::with_save_path(path.to_string()),
+                editor,
             }))
         }),
     )
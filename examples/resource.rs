@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use bevy_lookup_curve::{Knot, KnotInterpolation, LookupCurve, LookupCurveResource};
+
+/// Marker for the curve controlling the scale of the sprite
+struct PulseScale;
+
+/// This example stores a curve as a resource instead of loading it as an asset
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .insert_resource(LookupCurveResource::<PulseScale>::new(
+            LookupCurve::with_knots(vec![
+                Knot::at(0.0, 1.0).with_interpolation(KnotInterpolation::Cubic),
+                Knot::at(0.2, 1.5).with_interpolation(KnotInterpolation::Cubic),
+                Knot::at(1.0, 1.0),
+            ])
+            .unwrap(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, pulse)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn(Sprite::from_color(
+        Color::srgb(0.3, 0.7, 0.3),
+        Vec2::splat(100.0),
+    ));
+}
+
+fn pulse(
+    curve: Res<LookupCurveResource<PulseScale>>,
+    mut sprites: Query<&mut Transform, With<Sprite>>,
+    time: Res<Time>,
+) {
+    let scale = curve.lookup(time.elapsed_secs().fract());
+    for mut transform in &mut sprites {
+        transform.scale = Vec3::splat(scale);
+    }
+}
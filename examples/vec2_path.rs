@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPlugin};
+
+use bevy_lookup_curve::{
+    editor::Vec2LookupCurveEguiEditor, Knot, KnotInterpolation, LookupCurve, Vec2LookupCache,
+    Vec2LookupCurve,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(EguiPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, update)
+        .run();
+}
+
+#[derive(Component)]
+struct FollowPath {
+    t: f32,
+    speed: f32,
+}
+
+#[derive(Component)]
+struct Path(Vec2LookupCurve);
+
+#[derive(Component)]
+struct PathCache(Vec2LookupCache);
+
+#[derive(Component)]
+struct EditorWindow(Vec2LookupCurveEguiEditor);
+
+fn cubic(x: f32, y: f32) -> Knot {
+    Knot::at(x, y).with_interpolation(KnotInterpolation::Cubic)
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2d);
+
+    let path = Vec2LookupCurve::new(
+        LookupCurve::with_knots(vec![cubic(0.0, -400.0), cubic(0.5, 0.0), cubic(1.0, 400.0)])
+            .unwrap()
+            .with_name("x"),
+        LookupCurve::with_knots(vec![
+            cubic(0.0, -200.0),
+            cubic(0.25, 200.0),
+            cubic(0.75, -200.0),
+            cubic(1.0, 200.0),
+        ])
+        .unwrap()
+        .with_name("y"),
+    );
+
+    commands.spawn((
+        Sprite::from_image(asset_server.load("bevy_icon.png")),
+        Transform::from_scale(Vec3::splat(0.25)),
+        FollowPath { t: 0.0, speed: 0.2 },
+        PathCache(Vec2LookupCache::new()),
+        EditorWindow(Vec2LookupCurveEguiEditor::fitted_to_curve(&path)),
+        Path(path),
+    ));
+}
+
+fn update(
+    mut followers: Query<(
+        Entity,
+        &mut Transform,
+        &mut FollowPath,
+        &mut Path,
+        &mut PathCache,
+        &mut EditorWindow,
+    )>,
+    mut contexts: EguiContexts,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut follow, mut path, mut cache, mut editor) in followers.iter_mut()
+    {
+        // update t, looping back to the start
+        follow.t = (follow.t + follow.speed * time.delta_secs()) % 1.0;
+
+        // move sprite along the path
+        let position = path.0.sample_cached(follow.t, &mut cache.0);
+        transform.translation = position.extend(0.0);
+
+        // draw editor
+        editor.0.ui_window(
+            contexts.ctx_mut(),
+            entity,
+            "Path",
+            &mut path.0,
+            Some(follow.t),
+        );
+    }
+}
@@ -1,14 +1,64 @@
+use std::collections::{BTreeMap, HashMap};
+
+#[cfg(feature = "bake")]
+use bevy_app::Update;
 use bevy_app::{App, Plugin};
-use bevy_asset::{io::Reader, AssetApp, AssetLoader, LoadContext};
+use bevy_asset::{
+    io::{AsyncWriteExt, Reader, Writer},
+    processor::LoadTransformAndSave,
+    saver::{AssetSaver, SavedAsset},
+    transformer::IdentityAssetTransformer,
+    Asset, AssetApp, AssetLoader, Assets, Handle, LoadContext,
+};
+#[cfg(feature = "bake")]
+use bevy_asset::{AssetEvent, AssetId};
+use bevy_ecs::system::Resource;
+#[cfg(feature = "bake")]
+use bevy_ecs::{
+    event::EventReader,
+    system::{Res, ResMut},
+};
+use bevy_reflect::Reflect;
 
-use crate::{LookupCurve, LookupCurveLoadError};
+#[cfg(feature = "bake")]
+use crate::UniformBakedLookupTable;
+use crate::{LookupCurve, LookupCurveLoadError, LookupCurveSaveError, Vec2LookupCurve};
+#[cfg(feature = "bake")]
+use bevy_asset::transformer::{AssetTransformer, TransformedAsset};
 
 pub(crate) struct AssetPlugin;
 
 impl Plugin for AssetPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<LookupCurve>();
-        app.register_asset_loader(LookupCurveAssetLoader);
+        app.register_asset_loader(MultiFormatLookupCurveAssetLoader);
+        app.register_asset_processor::<LoadTransformAndSave<
+            MultiFormatLookupCurveAssetLoader,
+            IdentityAssetTransformer<LookupCurve>,
+            LookupCurveSaver,
+        >>(LookupCurveSaver.into());
+        app.init_asset::<Vec2LookupCurve>();
+        app.register_asset_loader(Vec2LookupCurveAssetLoader);
+        app.init_asset::<CurvePack>();
+        app.register_asset_loader(CurvePackAssetLoader);
+        app.init_resource::<CurveRegistry>();
+
+        #[cfg(feature = "bake")]
+        {
+            app.init_asset::<BakedLookupCurve>();
+            app.register_asset_loader(BakedLookupCurveAssetLoader);
+            app.register_asset_processor::<LoadTransformAndSave<
+                MultiFormatLookupCurveAssetLoader,
+                LookupCurveBakeTransformer,
+                BakedLookupCurveSaver,
+            >>(LoadTransformAndSave::new(
+                LookupCurveBakeTransformer,
+                BakedLookupCurveSaver,
+            ));
+
+            app.init_resource::<CurveLutMap>();
+            app.add_systems(Update, sync_curve_lut_textures);
+        }
     }
 }
 
@@ -28,11 +78,682 @@ impl AssetLoader for LookupCurveAssetLoader {
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
-        let lookup_curve = ron::de::from_bytes::<LookupCurve>(&bytes)?;
-        Ok(lookup_curve)
+        LookupCurve::from_ron_bytes(&bytes)
     }
 
     fn extensions(&self) -> &[&str] {
         &["curve.ron"]
     }
 }
+
+/// Loads a [LookupCurve] in any of the supported formats, dispatching on the file extension
+///
+/// - `.curve.ron`
+/// - `.curve.json` (requires the `json` feature)
+/// - `.curve.bin` (requires the `binary` feature), see [LookupCurve::to_binary]
+#[derive(Default)]
+pub struct MultiFormatLookupCurveAssetLoader;
+
+impl AssetLoader for MultiFormatLookupCurveAssetLoader {
+    type Asset = LookupCurve;
+    type Settings = ();
+    type Error = LookupCurveLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let path = load_context.path().to_string_lossy();
+        if path.ends_with(".curve.ron") {
+            return LookupCurve::from_ron_bytes(&bytes);
+        }
+        #[cfg(feature = "json")]
+        if path.ends_with(".curve.json") {
+            return Ok(serde_json::from_slice::<LookupCurve>(&bytes)?);
+        }
+        #[cfg(feature = "binary")]
+        if path.ends_with(".curve.bin") {
+            return Ok(LookupCurve::from_binary(&bytes)?);
+        }
+        Err(LookupCurveLoadError::UnsupportedExtension(
+            path.into_owned(),
+        ))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[
+            "curve.ron",
+            #[cfg(feature = "json")]
+            "curve.json",
+            #[cfg(feature = "binary")]
+            "curve.bin",
+        ]
+    }
+}
+
+#[derive(Default)]
+pub struct Vec2LookupCurveAssetLoader;
+
+impl AssetLoader for Vec2LookupCurveAssetLoader {
+    type Asset = Vec2LookupCurve;
+    type Settings = ();
+    type Error = LookupCurveLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes::<Vec2LookupCurve>(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["vec2curve.ron"]
+    }
+}
+
+/// Saves a [LookupCurve] as RON, for use with the Bevy asset processor
+///
+/// Registered as a `LoadTransformAndSave` processor with an identity transform, which re-bakes the curve to the same format as [LookupCurve::save_to_file].
+#[derive(Default)]
+pub struct LookupCurveSaver;
+
+impl AssetSaver for LookupCurveSaver {
+    type Asset = LookupCurve;
+    type Settings = ();
+    type OutputLoader = MultiFormatLookupCurveAssetLoader;
+    type Error = LookupCurveSaveError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _settings: &(),
+    ) -> Result<(), Self::Error> {
+        let s = asset.to_ron_string()?;
+        writer.write_all(s.as_bytes()).await?;
+        Ok(())
+    }
+}
+
+/// A [LookupCurve] baked into a [UniformBakedLookupTable] by the asset processor, see [LookupCurveBakeTransformer]
+#[cfg(feature = "bake")]
+#[derive(Asset, Reflect, Debug, Clone, Default)]
+pub struct BakedLookupCurve {
+    pub table: UniformBakedLookupTable,
+}
+
+#[cfg(feature = "bake")]
+impl BakedLookupCurve {
+    /// Find y for given x, see [UniformBakedLookupTable::lookup]
+    #[inline]
+    pub fn lookup(&self, x: f32) -> f32 {
+        self.table.lookup(x)
+    }
+}
+
+/// Settings for [LookupCurveBakeTransformer]
+#[cfg(feature = "bake")]
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize)]
+pub struct LookupCurveBakeSettings {
+    /// Number of samples in the baked table
+    pub resolution: u32,
+}
+
+#[cfg(feature = "bake")]
+impl Default for LookupCurveBakeSettings {
+    fn default() -> Self {
+        Self { resolution: 256 }
+    }
+}
+
+/// Bakes a [LookupCurve] into a [BakedLookupCurve] at build time, using [LookupCurve::bake_uniform]
+///
+/// Registered as a `LoadTransformAndSave` processor together with [BakedLookupCurveSaver]. Select it in the `.meta` file of a curve to
+/// have the asset processor bake it, without any custom build scripts.
+#[cfg(feature = "bake")]
+#[derive(Default)]
+pub struct LookupCurveBakeTransformer;
+
+#[cfg(feature = "bake")]
+impl AssetTransformer for LookupCurveBakeTransformer {
+    type AssetInput = LookupCurve;
+    type AssetOutput = BakedLookupCurve;
+    type Settings = LookupCurveBakeSettings;
+    type Error = std::convert::Infallible;
+
+    async fn transform<'a>(
+        &'a self,
+        asset: TransformedAsset<Self::AssetInput>,
+        settings: &'a Self::Settings,
+    ) -> Result<TransformedAsset<Self::AssetOutput>, Self::Error> {
+        let table = asset.bake_uniform(settings.resolution as usize);
+        Ok(asset.replace_asset(BakedLookupCurve { table }))
+    }
+}
+
+/// Saves a [BakedLookupCurve] as RON
+#[cfg(feature = "bake")]
+#[derive(Default)]
+pub struct BakedLookupCurveSaver;
+
+#[cfg(feature = "bake")]
+impl AssetSaver for BakedLookupCurveSaver {
+    type Asset = BakedLookupCurve;
+    type Settings = ();
+    type OutputLoader = BakedLookupCurveAssetLoader;
+    type Error = LookupCurveSaveError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _settings: &(),
+    ) -> Result<(), Self::Error> {
+        let s = crate::to_pretty_ron(&asset.table)?;
+        writer.write_all(s.as_bytes()).await?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bake")]
+#[derive(Default)]
+pub struct BakedLookupCurveAssetLoader;
+
+#[cfg(feature = "bake")]
+impl AssetLoader for BakedLookupCurveAssetLoader {
+    type Asset = BakedLookupCurve;
+    type Settings = ();
+    type Error = LookupCurveLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let table = ron::de::from_bytes::<UniformBakedLookupTable>(&bytes)?;
+        Ok(BakedLookupCurve { table })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["bakedcurve.ron"]
+    }
+}
+
+/// A collection of named lookup curves loaded from a single `.curvepack.ron` file
+///
+/// Each curve is also available as a labeled sub-asset, for example `asset_server.load("my.curvepack.ron#speed")`.
+#[derive(Asset, Reflect, Debug, Default)]
+pub struct CurvePack {
+    /// Handles to the curves in the pack, by label
+    pub curves: HashMap<String, Handle<LookupCurve>>,
+}
+
+impl CurvePack {
+    /// Returns the handle to the curve with the given label
+    pub fn get(&self, label: &str) -> Option<&Handle<LookupCurve>> {
+        self.curves.get(label)
+    }
+}
+
+/// File representation of a [CurvePack]
+#[derive(serde::Deserialize)]
+struct CurvePackData {
+    curves: BTreeMap<String, LookupCurve>,
+}
+
+#[derive(Default)]
+pub struct CurvePackAssetLoader;
+
+impl AssetLoader for CurvePackAssetLoader {
+    type Asset = CurvePack;
+    type Settings = ();
+    type Error = LookupCurveLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let data = ron::de::from_bytes::<CurvePackData>(&bytes)?;
+
+        let curves = data
+            .curves
+            .into_iter()
+            .map(|(label, mut curve)| {
+                if curve.name.is_none() {
+                    curve.name = Some(label.clone());
+                }
+                let handle = load_context.labeled_asset_scope(label.clone(), |_| curve);
+                (label, handle)
+            })
+            .collect();
+
+        Ok(CurvePack { curves })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["curvepack.ron"]
+    }
+}
+
+/// Global registry of [LookupCurve] handles by name, for data-driven references to curves, e.g. from scripts or config files
+///
+/// Inserted as an empty resource by [LookupCurvePlugin](crate::LookupCurvePlugin).
+#[derive(Resource, Debug, Default)]
+pub struct CurveRegistry {
+    curves: HashMap<String, Handle<LookupCurve>>,
+}
+
+impl CurveRegistry {
+    /// Registers `handle` under `name`, replacing any previously registered handle with the same name
+    pub fn register(&mut self, name: &str, handle: Handle<LookupCurve>) {
+        self.curves.insert(name.to_owned(), handle);
+    }
+
+    /// Returns the handle registered under `name`
+    pub fn get(&self, name: &str) -> Option<&Handle<LookupCurve>> {
+        self.curves.get(name)
+    }
+
+    /// Find y for given x on the curve registered under `name`, see [LookupCurve::lookup]
+    ///
+    /// Returns `None` if no curve is registered under `name`, or if the curve is not loaded.
+    pub fn lookup(&self, name: &str, x: f32, curves: &Assets<LookupCurve>) -> Option<f32> {
+        curves.get(self.get(name)?).map(|curve| curve.lookup(x))
+    }
+}
+
+/// Texture format of the lookup tables baked by [bake_multi_to_image]
+#[cfg(feature = "bevy_image")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LutImageFormat {
+    /// 8 bits per channel, values are clamped to `[0, 1]`
+    #[default]
+    Rgba8Unorm,
+    /// 32-bit float per channel, values are stored as is
+    Rgba32Float,
+}
+
+/// Bakes up to four curves into the R, G, B and A channels of a `1 × resolution` texture, one texel per sample.
+///
+/// All curves are sampled over the union of their domains, with row `0` at the start of the domain.
+/// Curves with a shorter domain are extrapolated like [LookupCurve::lookup], and unused channels are left at `0`.
+///
+/// In a shader, sample the texture with `t` in `[0, 1]` along the v-axis, e.g.:
+///
+/// ```wgsl
+/// @group(2) @binding(0) var lut_texture: texture_2d<f32>;
+/// @group(2) @binding(1) var lut_sampler: sampler;
+///
+/// let values = textureSample(lut_texture, lut_sampler, vec2(0.5, t));
+/// let first_curve = values.r;
+/// ```
+///
+/// Use a sampler with [ClampToEdge](wgpu_types::AddressMode::ClampToEdge) addressing to avoid wrapping at the ends of the domain.
+///
+/// # Panics
+/// Panics if `curves` contains more than four curves, or if `resolution` is `0`.
+#[cfg(feature = "bevy_image")]
+pub fn bake_multi_to_image(
+    curves: &[&LookupCurve],
+    resolution: u32,
+    format: LutImageFormat,
+) -> bevy_image::Image {
+    use wgpu_types::{Extent3d, TextureDimension, TextureFormat};
+
+    assert!(curves.len() <= 4, "at most four curves can be baked");
+    assert!(resolution > 0, "resolution must be at least 1");
+
+    let (min_x, max_x) = curves
+        .iter()
+        .filter(|curve| !curve.knots().is_empty())
+        .map(|curve| curve.domain())
+        .reduce(|(a_min, a_max), (b_min, b_max)| (a_min.min(b_min), a_max.max(b_max)))
+        .unwrap_or((0.0, 1.0));
+
+    let divisor = (resolution - 1).max(1) as f32;
+    let texels = (0..resolution).map(|i| {
+        let x = min_x + (max_x - min_x) * i as f32 / divisor;
+        let mut texel = [0.0; 4];
+        for (channel, curve) in texel.iter_mut().zip(curves) {
+            *channel = curve.lookup(x);
+        }
+        texel
+    });
+
+    let (data, texture_format) = match format {
+        LutImageFormat::Rgba8Unorm => (
+            texels
+                .flatten()
+                .map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8)
+                .collect(),
+            TextureFormat::Rgba8Unorm,
+        ),
+        LutImageFormat::Rgba32Float => (
+            texels.flatten().flat_map(f32::to_le_bytes).collect(),
+            TextureFormat::Rgba32Float,
+        ),
+    };
+
+    bevy_image::Image::new(
+        Extent3d {
+            width: 1,
+            height: resolution,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        texture_format,
+        bevy_asset::RenderAssetUsages::default(),
+    )
+}
+
+/// Lookup table textures of all loaded [LookupCurve] assets, by asset id
+///
+/// Kept up to date by the `sync_curve_lut_textures` system, which re-bakes a curve with [bake_multi_to_image] whenever it is added or modified.
+/// Each texture is `1 × CurveLutMap::RESOLUTION` texels of [LutImageFormat::Rgba32Float], with the curve in the red channel.
+/// Look up the texture of a curve to bind it in a material, e.g. `lut_map.get(&curve_handle)`.
+#[cfg(feature = "bake")]
+#[derive(Resource, Debug, Default)]
+pub struct CurveLutMap(pub HashMap<AssetId<LookupCurve>, Handle<bevy_image::Image>>);
+
+#[cfg(feature = "bake")]
+impl CurveLutMap {
+    /// Number of samples in each baked texture
+    pub const RESOLUTION: u32 = 256;
+
+    /// Returns the lookup table texture of the given curve
+    pub fn get(
+        &self,
+        curve: impl Into<AssetId<LookupCurve>>,
+    ) -> Option<&Handle<bevy_image::Image>> {
+        self.0.get(&curve.into())
+    }
+}
+
+#[cfg(feature = "bake")]
+fn sync_curve_lut_textures(
+    mut events: EventReader<AssetEvent<LookupCurve>>,
+    curves: Res<Assets<LookupCurve>>,
+    images: Option<ResMut<Assets<bevy_image::Image>>>,
+    mut lut_map: ResMut<CurveLutMap>,
+) {
+    // Images are only available together with the render plugins
+    let Some(mut images) = images else {
+        events.clear();
+        return;
+    };
+
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                let Some(curve) = curves.get(*id) else {
+                    continue;
+                };
+                let image = bake_multi_to_image(
+                    &[curve],
+                    CurveLutMap::RESOLUTION,
+                    LutImageFormat::Rgba32Float,
+                );
+                match lut_map.0.get(id) {
+                    Some(handle) => images.insert(handle, image),
+                    None => {
+                        lut_map.0.insert(*id, images.add(image));
+                    }
+                }
+            }
+            AssetEvent::Removed { id } => {
+                if let Some(handle) = lut_map.0.remove(id) {
+                    images.remove(&handle);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_asset::AssetServer;
+
+    /// Constructs an app with the asset plugins, loading assets from a temporary directory containing `files`
+    fn app_with_asset_files<C: AsRef<[u8]>>(dir_name: &str, files: &[(&str, C)]) -> App {
+        let dir = std::env::temp_dir().join(dir_name);
+        std::fs::create_dir_all(&dir).unwrap();
+        for (path, contents) in files {
+            std::fs::write(dir.join(path), contents).unwrap();
+        }
+
+        bevy_tasks::ComputeTaskPool::get_or_init(Default::default);
+        bevy_tasks::AsyncComputeTaskPool::get_or_init(Default::default);
+        bevy_tasks::IoTaskPool::get_or_init(Default::default);
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_asset::AssetPlugin {
+                file_path: dir.to_string_lossy().into_owned(),
+                ..Default::default()
+            },
+            AssetPlugin,
+        ));
+        app
+    }
+
+    /// Loads the asset at `path`, updating the app until it is loaded
+    fn load<A: Asset>(app: &mut App, path: &str) -> Handle<A> {
+        let handle = app.world().resource::<AssetServer>().load(path);
+        for _ in 0..1000 {
+            bevy_tasks::tick_global_task_pools_on_main_thread();
+            app.update();
+            if app
+                .world()
+                .resource::<AssetServer>()
+                .is_loaded_with_dependencies(&handle)
+            {
+                return handle;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        panic!("timed out loading {}", path);
+    }
+
+    #[test]
+    fn curve_pack_loads_labeled_curves() {
+        let mut app = app_with_asset_files(
+            "bevy_lookup_curve_curve_pack",
+            &[(
+                "test.curvepack.ron",
+                r#"(
+  curves: {
+    "speed": (knots: [(position: (0.0, 0.0), interpolation: Linear, left_tangent: (slope: 0.0, mode: Aligned), right_tangent: (slope: 0.0, mode: Aligned)), (position: (1.0, 2.0), interpolation: Linear, left_tangent: (slope: 0.0, mode: Aligned), right_tangent: (slope: 0.0, mode: Aligned))]),
+    "height": (knots: [(position: (0.0, 1.0), interpolation: Constant, left_tangent: (slope: 0.0, mode: Aligned), right_tangent: (slope: 0.0, mode: Aligned))], name: Some("Jump height")),
+  },
+)"#,
+            )],
+        );
+
+        let pack = load::<CurvePack>(&mut app, "test.curvepack.ron");
+        let world = app.world();
+        let pack = world.resource::<Assets<CurvePack>>().get(&pack).unwrap();
+        let curves = world.resource::<Assets<LookupCurve>>();
+        assert_eq!(pack.curves.len(), 2);
+        assert!(pack.get("missing").is_none());
+
+        let speed = curves.get(pack.get("speed").unwrap()).unwrap();
+        assert_eq!(speed.lookup(0.5), 1.0);
+        assert_eq!(speed.name.as_deref(), Some("speed"));
+        let height = curves.get(pack.get("height").unwrap()).unwrap();
+        assert_eq!(height.name.as_deref(), Some("Jump height"));
+
+        // Curves are labeled sub-assets of the pack
+        let labeled: Handle<LookupCurve> = world
+            .resource::<AssetServer>()
+            .load("test.curvepack.ron#speed");
+        assert_eq!(labeled.id(), pack.get("speed").unwrap().id());
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn multi_format_loader_loads_binary() {
+        let curve = LookupCurve::from_step_function(&[(0.0, 1.0), (0.5, 3.0)]).with_name("steps");
+        let mut app = app_with_asset_files(
+            "bevy_lookup_curve_binary",
+            &[("steps.curve.bin", curve.to_binary())],
+        );
+
+        let handle = load::<LookupCurve>(&mut app, "steps.curve.bin");
+        let loaded = app
+            .world()
+            .resource::<Assets<LookupCurve>>()
+            .get(&handle)
+            .unwrap();
+        assert_eq!(loaded.name.as_deref(), Some("steps"));
+        assert_eq!(loaded.lookup(0.25), 1.0);
+        assert_eq!(loaded.lookup(0.75), 3.0);
+    }
+
+    #[test]
+    fn curve_registry_looks_up_by_name() {
+        let mut curves = Assets::<LookupCurve>::default();
+        let mut registry = CurveRegistry::default();
+        let constant = |y| LookupCurve::from_step_function(&[(0.0, y)]);
+
+        registry.register("speed", curves.add(constant(1.0)));
+        assert_eq!(registry.lookup("speed", 0.5, &curves), Some(1.0));
+        assert_eq!(registry.lookup("height", 0.5, &curves), None);
+
+        // Registering the same name again replaces the handle
+        let handle = curves.add(constant(2.0));
+        registry.register("speed", handle.clone());
+        assert_eq!(registry.get("speed"), Some(&handle));
+        assert_eq!(registry.lookup("speed", 0.5, &curves), Some(2.0));
+
+        // Unloaded curves can not be looked up
+        curves.remove(&handle);
+        assert_eq!(registry.lookup("speed", 0.5, &curves), None);
+    }
+
+    #[cfg(feature = "bevy_image")]
+    #[test]
+    fn bake_multi_to_image_packs_channels() {
+        use crate::Knot;
+
+        let rising = LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)]).unwrap();
+        let falling =
+            LookupCurve::with_knots(vec![Knot::at(1.0, 2.0), Knot::at(2.0, -1.0)]).unwrap();
+
+        let image = bake_multi_to_image(&[&rising, &falling], 3, LutImageFormat::Rgba32Float);
+        assert_eq!(image.texture_descriptor.size.width, 1);
+        assert_eq!(image.texture_descriptor.size.height, 3);
+        let texels: Vec<f32> = image
+            .data
+            .chunks_exact(4)
+            .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+            .collect();
+        // Sampled at x = 0, 1 and 2, over the union of the domains
+        assert_eq!(
+            texels,
+            [0.0, 2.0, 0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 1.0, -1.0, 0.0, 0.0]
+        );
+
+        let image = bake_multi_to_image(&[&rising, &falling], 3, LutImageFormat::Rgba8Unorm);
+        assert_eq!(image.data, [0, 255, 0, 0, 255, 255, 0, 0, 255, 0, 0, 0]);
+    }
+
+    #[cfg(feature = "bake")]
+    #[test]
+    fn bake_transformer_bakes_curve() {
+        use crate::Knot;
+        use bevy_asset::LoadedAsset;
+
+        let curve = LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.0).with_interpolation(crate::KnotInterpolation::Cubic),
+            Knot::at(2.0, 1.0),
+        ])
+        .unwrap();
+        let asset = TransformedAsset::from_loaded(LoadedAsset::from(curve.clone()).into()).unwrap();
+        let settings = LookupCurveBakeSettings { resolution: 65 };
+        let baked =
+            bevy_tasks::block_on(LookupCurveBakeTransformer.transform(asset, &settings)).unwrap();
+
+        assert_eq!(baked.table.ys.len(), 65);
+        for i in 0..=40 {
+            let x = i as f32 / 20.0;
+            assert!((baked.lookup(x) - curve.lookup(x)).abs() < 1e-3);
+        }
+
+        // Saved in the format read by BakedLookupCurveAssetLoader
+        let saved = crate::to_pretty_ron(&baked.table).unwrap();
+        let loaded = ron::de::from_str::<UniformBakedLookupTable>(&saved).unwrap();
+        assert_eq!(loaded.ys, baked.table.ys);
+    }
+
+    #[cfg(feature = "bake")]
+    #[test]
+    fn lut_textures_follow_curve_changes() {
+        use crate::Knot;
+        use bevy_image::Image;
+
+        let mut app = app_with_asset_files::<&str>("bevy_lookup_curve_lut_textures", &[]);
+        app.init_asset::<Image>();
+        let red_channel = |app: &App, handle: &Handle<LookupCurve>| {
+            let lut_map = app.world().resource::<CurveLutMap>();
+            let image = app
+                .world()
+                .resource::<Assets<Image>>()
+                .get(lut_map.get(handle)?)?;
+            let texel =
+                |i: usize| f32::from_le_bytes(image.data[16 * i..16 * i + 4].try_into().unwrap());
+            Some((texel(0), texel(CurveLutMap::RESOLUTION as usize - 1)))
+        };
+
+        let curve = LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)]).unwrap();
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<LookupCurve>>()
+            .add(curve);
+        app.update();
+        app.update();
+        assert_eq!(red_channel(&app, &handle), Some((0.0, 1.0)));
+        let texture = app.world().resource::<CurveLutMap>().get(&handle).cloned();
+
+        // Modified curves are re-baked into the same texture
+        app.world_mut()
+            .resource_mut::<Assets<LookupCurve>>()
+            .get_mut(&handle)
+            .unwrap()
+            .get_knot_mut(1)
+            .position
+            .y = 2.0;
+        app.update();
+        app.update();
+        assert_eq!(red_channel(&app, &handle), Some((0.0, 2.0)));
+        assert_eq!(
+            app.world().resource::<CurveLutMap>().get(&handle).cloned(),
+            texture
+        );
+
+        app.world_mut()
+            .resource_mut::<Assets<LookupCurve>>()
+            .remove(&handle);
+        app.update();
+        app.update();
+        assert!(app.world().resource::<CurveLutMap>().get(&handle).is_none());
+        assert_eq!(app.world().resource::<Assets<Image>>().len(), 0);
+    }
+}
@@ -0,0 +1,182 @@
+use base64::Engine;
+use bevy_math::Vec2;
+
+use crate::{Knot, KnotEvent, KnotInterpolation, LookupCurve, Tangent};
+
+/// Error returned by [LookupCurve::from_base64] and [LookupCurve::from_binary]
+#[non_exhaustive]
+#[derive(Debug, thiserror::Error)]
+pub enum DecodeError {
+    /// The string is not valid base64
+    #[error("Invalid base64 for lookup curve: {0}")]
+    Base64(#[from] base64::DecodeError),
+    /// A [postcard] Error
+    #[error("Could not deserialize lookup curve: {0}")]
+    Postcard(#[from] postcard::Error),
+}
+
+/// Binary representation of a [Knot]
+///
+/// postcard is not self-describing, so the binary format can not use the skipped and optional fields of the RON format.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BinaryKnot {
+    position: Vec2,
+    interpolation: KnotInterpolation,
+    left_tangent: Tangent,
+    right_tangent: Tangent,
+    annotation: Option<String>,
+    locked: bool,
+    anchor: bool,
+}
+
+/// Binary representation of a [LookupCurve], see [BinaryKnot]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BinaryCurve {
+    knots: Vec<BinaryKnot>,
+    output_clamp: Option<(f32, f32)>,
+    tension: f32,
+    name: Option<String>,
+    events: Vec<KnotEvent>,
+    miniature_resolution: u32,
+}
+
+impl LookupCurve {
+    /// Serializes the curve to a compact base64 string, for embedding curves as opaque strings in e.g. JSON or YAML config files.
+    ///
+    /// The curve is encoded with [postcard] before being base64 encoded. Knot ids and solver settings are not included.
+    ///
+    /// **Note:** Unlike the RON format, the encoding is not stable across versions of the crate. It changes whenever a field is added to the curve,
+    /// or if the postcard wire format changes. Use [LookupCurve::save_to_file] for long-term storage.
+    pub fn to_base64(&self) -> String {
+        base64::engine::general_purpose::STANDARD.encode(self.to_binary())
+    }
+
+    /// Deserializes a curve from a string created by [LookupCurve::to_base64] with the same version of the crate
+    pub fn from_base64(s: &str) -> Result<LookupCurve, DecodeError> {
+        let bytes = base64::engine::general_purpose::STANDARD.decode(s.trim())?;
+        LookupCurve::from_binary(&bytes)
+    }
+
+    /// Serializes the curve to [postcard] bytes, as loaded from `.curve.bin` files by the asset loader.
+    ///
+    /// The same caveats as for [LookupCurve::to_base64] apply: knot ids and solver settings are not included, and the encoding is not stable across versions of the crate.
+    pub fn to_binary(&self) -> Vec<u8> {
+        let curve = BinaryCurve {
+            knots: self
+                .knots
+                .iter()
+                .map(|knot| BinaryKnot {
+                    position: knot.position,
+                    interpolation: knot.interpolation,
+                    left_tangent: knot.left_tangent,
+                    right_tangent: knot.right_tangent,
+                    annotation: knot.annotation.clone(),
+                    locked: knot.locked,
+                    anchor: knot.anchor,
+                })
+                .collect(),
+            output_clamp: self.output_clamp,
+            tension: self.tension,
+            name: self.name.clone(),
+            events: self.events.clone(),
+            miniature_resolution: self.miniature_resolution,
+        };
+        postcard::to_stdvec(&curve).expect("serializing to a Vec can not fail")
+    }
+
+    /// Deserializes a curve from bytes created by [LookupCurve::to_binary] with the same version of the crate
+    pub fn from_binary(bytes: &[u8]) -> Result<LookupCurve, DecodeError> {
+        let curve: BinaryCurve = postcard::from_bytes(bytes)?;
+
+        let knots = curve
+            .knots
+            .into_iter()
+            .map(|knot| Knot {
+                position: knot.position,
+                interpolation: knot.interpolation,
+                left_tangent: knot.left_tangent,
+                right_tangent: knot.right_tangent,
+                annotation: knot.annotation,
+                locked: knot.locked,
+                anchor: knot.anchor,
+                ..Default::default()
+            })
+            .collect();
+
+        Ok(LookupCurve {
+            output_clamp: curve.output_clamp,
+            tension: curve.tension,
+            name: curve.name,
+            events: curve.events,
+            miniature_resolution: curve.miniature_resolution,
+            ..LookupCurve::new_unchecked(knots)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_roundtrip() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.2),
+                interpolation: KnotInterpolation::Cubic,
+                right_tangent: Tangent {
+                    slope: 2.0,
+                    weight: Some(0.4),
+                    ..Default::default()
+                },
+                annotation: Some("start".to_string()),
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.9),
+                interpolation: KnotInterpolation::HermiteRaw {
+                    tangent_in: Vec2::new(1.0, 0.5),
+                    tangent_out: Vec2::new(1.0, -0.5),
+                },
+                locked: true,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(2.0, 0.4),
+                anchor: true,
+                ..Default::default()
+            },
+        ])
+        .unwrap()
+        .with_output_clamp(0.0, 0.8)
+        .with_name("fade")
+        .with_events(vec![KnotEvent::new(0.5, "halfway")]);
+
+        let encoded = curve.to_base64();
+        assert!(!encoded.contains(['(', '{', '\n']));
+
+        let decoded = LookupCurve::from_base64(&encoded).unwrap();
+        assert_eq!(decoded.name.as_deref(), Some("fade"));
+        assert_eq!(decoded.output_clamp, Some((0.0, 0.8)));
+        assert_eq!(decoded.events, curve.events);
+        assert_eq!(decoded.knots().len(), 3);
+        assert_eq!(decoded.knots()[0].annotation.as_deref(), Some("start"));
+        assert!(decoded.knots()[1].locked);
+        assert!(decoded.knots()[2].anchor);
+        for x in [0.25, 0.5, 1.5] {
+            assert_eq!(decoded.lookup(x), curve.lookup(x));
+        }
+    }
+
+    #[test]
+    fn base64_decode_errors() {
+        assert!(matches!(
+            LookupCurve::from_base64("not base64!"),
+            Err(DecodeError::Base64(_))
+        ));
+        assert!(matches!(
+            LookupCurve::from_base64("AAAA"),
+            Err(DecodeError::Postcard(_))
+        ));
+    }
+}
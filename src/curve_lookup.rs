@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::{LookupCurve, UniformBakedLookupTable};
+
+/// Common interface for evaluating different kinds of curves, allowing them to be stored as trait objects.
+///
+/// See [DynCurve] for mixing curve types in a single ECS query.
+pub trait CurveLookup: Send + Sync {
+    /// Find y for given x on the curve
+    fn lookup(&self, x: f32) -> f32;
+}
+
+impl CurveLookup for LookupCurve {
+    #[inline]
+    fn lookup(&self, x: f32) -> f32 {
+        LookupCurve::lookup(self, x)
+    }
+}
+
+impl CurveLookup for UniformBakedLookupTable {
+    #[inline]
+    fn lookup(&self, x: f32) -> f32 {
+        UniformBakedLookupTable::lookup(self, x)
+    }
+}
+
+impl<T: CurveLookup + ?Sized> CurveLookup for Arc<T> {
+    #[inline]
+    fn lookup(&self, x: f32) -> f32 {
+        (**self).lookup(x)
+    }
+}
+
+/// Any [CurveLookup] behind a box, e.g. to let entities in the same query use either a live [LookupCurve] or a [UniformBakedLookupTable]
+#[cfg_attr(feature = "bevy_ecs", derive(bevy_ecs::component::Component))]
+pub struct DynCurve(pub Box<dyn CurveLookup>);
+
+impl DynCurve {
+    pub fn new(curve: impl CurveLookup + 'static) -> Self {
+        Self(Box::new(curve))
+    }
+
+    /// Find y for given x on the curve
+    #[inline]
+    pub fn lookup(&self, x: f32) -> f32 {
+        self.0.lookup(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Knot, KnotInterpolation};
+    use bevy_math::Vec2;
+
+    #[test]
+    fn dyn_curves_match() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(0.5, 1.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.25),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let live = DynCurve::new(curve.clone());
+        let baked = DynCurve::new(curve.bake_uniform(11));
+        let shared = DynCurve::new(Arc::new(curve) as Arc<dyn CurveLookup>);
+        for i in 0..=100 {
+            let x = i as f32 / 100.0;
+            assert!((live.lookup(x) - baked.lookup(x)).abs() < 1e-5);
+            assert_eq!(live.lookup(x), shared.lookup(x));
+        }
+    }
+}
@@ -39,18 +39,17 @@ impl LookupCurveEditor {
     }
 
     /// Constructs a [LookupCurveEditor] with the supplied `curve_handle` and `path` as save path.
+    ///
+    /// The editor state is restored from the sidecar file next to `path` if it exists, see [LookupCurveEguiEditor::with_save_path].
     pub fn with_save_path(curve_handle: Handle<LookupCurve>, path: String) -> Self {
         Self {
-            egui_editor: LookupCurveEguiEditor {
-                ron_path: Some(path),
-                ..Default::default()
-            },
+            egui_editor: LookupCurveEguiEditor::with_save_path(path),
             ..LookupCurveEditor::new(curve_handle)
         }
     }
 }
 
-fn lookup_curve_editor_ui(
+pub(crate) fn lookup_curve_editor_ui(
     mut editors: Query<(Entity, &mut LookupCurveEditor)>,
     mut contexts: EguiContexts,
     mut curves: ResMut<Assets<LookupCurve>>,
@@ -58,6 +57,8 @@ fn lookup_curve_editor_ui(
     for (entity, mut editor) in &mut editors {
         if let Some(curve) = curves.get_mut(&editor.curve_handle) {
             let sample = editor.sample;
+            // Cleared in case the window is collapsed and the handles are not drawn
+            editor.egui_editor.knot_handle_positions.clear();
             editor
                 .egui_editor
                 .ui_window(contexts.ctx_mut(), entity, curve, sample);
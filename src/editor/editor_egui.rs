@@ -4,24 +4,232 @@ use egui::{
     Ui,
 };
 
-use crate::{Knot, KnotInterpolation, LookupCurve, TangentMode, TangentSide};
+use std::collections::HashMap;
+#[cfg(feature = "ron")]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    slope_weight_from_bezier, CurvePreset, Knot, KnotInterpolation, LookupCache, LookupCurve,
+    Tangent, TangentMode, TangentSide, Vec2LookupCurve,
+};
+
+/// Controls when tangent handles are displayed in the [LookupCurveEguiEditor]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum TangentVisibility {
+    /// Tangents are displayed for all knots
+    #[default]
+    Always,
+    /// Tangents are only displayed for selected knots
+    OnSelect,
+    /// Tangents are never displayed
+    Never,
+}
+
+/// Error returned when saving or loading the state of a [LookupCurveEguiEditor]
+#[cfg(feature = "ron")]
+#[non_exhaustive]
+#[derive(Debug, thiserror::Error)]
+pub enum EditorStateError {
+    /// An [IO](std::io) Error
+    #[error("Could not access editor state: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON](ron) Error when parsing
+    #[error("Could not parse RON for editor state: {0}")]
+    RonSpannedError(#[from] ron::error::SpannedError),
+    /// A [RON](ron) Error when serializing
+    #[error("Could not serialize editor state to RON: {0}")]
+    RonError(#[from] ron::error::Error),
+}
+
+/// How the playback of the [LookupCurveEguiEditor] continues when reaching the end of the curve
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum ExtrapolationMode {
+    /// Stop at the end of the curve
+    Clamp,
+    /// Start over from the beginning of the curve
+    #[default]
+    Repeat,
+    /// Alternate between playing forwards and backwards
+    PingPong,
+}
+
+/// Built-in sample playback of the [LookupCurveEguiEditor], for previewing a curve as an animation
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct PlaybackState {
+    /// Time since the start of the playback, in units of x
+    pub t: f32,
+    /// Units of x advanced per second
+    pub speed: f32,
+    pub loop_mode: ExtrapolationMode,
+    pub paused: bool,
+}
+
+impl Default for PlaybackState {
+    fn default() -> Self {
+        Self {
+            t: 0.0,
+            speed: 1.0,
+            loop_mode: ExtrapolationMode::default(),
+            paused: false,
+        }
+    }
+}
+
+impl PlaybackState {
+    /// Returns the x-position of the sample within the `(min_x, max_x)` domain, according to the `loop_mode`
+    pub fn sample_x(&self, (min_x, max_x): (f32, f32)) -> f32 {
+        let length = max_x - min_x;
+        if length <= 0.0 {
+            return min_x;
+        }
+        let offset = match self.loop_mode {
+            ExtrapolationMode::Clamp => self.t.clamp(0.0, length),
+            ExtrapolationMode::Repeat => self.t.rem_euclid(length),
+            ExtrapolationMode::PingPong => {
+                length - (self.t.rem_euclid(2.0 * length) - length).abs()
+            }
+        };
+        min_x + offset
+    }
+}
 
+/// State of a [LookupCurve] before a whole-curve edit in the [LookupCurveEguiEditor], see [LookupCurveEguiEditor::undo_history]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct UndoStep {
+    pub knots: Vec<Knot>,
+    pub output_clamp: Option<(f32, f32)>,
+}
+
+impl UndoStep {
+    pub fn new(curve: &LookupCurve) -> Self {
+        Self {
+            knots: curve.knots().to_vec(),
+            output_clamp: curve.output_clamp,
+        }
+    }
+
+    /// Restores the knots and output clamp of `curve` to this step
+    pub fn restore(self, curve: &mut LookupCurve) {
+        curve.knots = self.knots;
+        curve.output_clamp = self.output_clamp;
+    }
+}
+
+/// Controls how tangent slopes are displayed and edited in the [LookupCurveEguiEditor]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum TangentDisplay {
+    /// Rise over run, as stored in [Tangent::slope](crate::Tangent::slope)
+    #[default]
+    Slope,
+    /// Angle from the x-axis in degrees, in curve space
+    Degrees,
+}
+
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 /// Lookup curve editor implemented using `egui`.
 ///
-/// Holds the editor state.
+/// Holds the editor state. Preferences like the viewport and grid can be persisted using [LookupCurveEguiEditor::save_state],
+/// while transient state like the selection and undo history is not serialized.
 pub struct LookupCurveEguiEditor {
     pub offset: Vec2,
     pub scale: Vec2,
+    /// Smallest scale reachable by zooming
+    pub min_scale: Vec2,
+    /// Largest scale reachable by zooming
+    pub max_scale: Vec2,
+    /// Animate zooming towards the target scale over a few frames instead of jumping directly
+    pub smooth_zoom: bool,
+    /// Scale being approached when [LookupCurveEguiEditor::smooth_zoom] is enabled
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub zoom_target: Option<Vec2>,
 
     pub grid_step_x: f32,
     pub grid_step_y: f32,
 
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub editor_size: Vec2,
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub hover_point: Vec2,
 
+    /// Recompute tangents of the moved knot and its neighbours when a knot is moved, see [LookupCurve::modify_knot_smooth]
+    pub auto_tangents: bool,
+
+    /// When to display tangent handles
+    pub tangent_visibility: TangentVisibility,
+    /// How tangent slopes are displayed in the tangent context menu
+    pub tangent_display: TangentDisplay,
+    /// Ids of the currently selected knots
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub selected_knots: Vec<usize>,
+
+    /// Preset waiting for confirmation before replacing the curve
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub pending_preset: Option<CurvePreset>,
+    /// State of the curve before each whole-curve edit, like replacing it with a preset, most recent last
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub undo_history: Vec<UndoStep>,
+    /// CSV pasted into the "Import from Unreal" window, `None` while the window is closed. See [LookupCurve::from_unreal_curve_csv].
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub unreal_import: Option<String>,
+    /// Tangents (left, right) of knots switched away from [KnotInterpolation::Cubic] by knot id, restored when switching back
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub saved_tangents: HashMap<usize, (Tangent, Tangent)>,
+
     #[cfg(feature = "ron")]
     pub ron_path: Option<String>,
+    /// Number of backups kept by "Save with backup", see [LookupCurve::save_to_file_versioned]
+    #[cfg(feature = "ron")]
+    pub max_versions: usize,
+
+    /// Set by the file watcher when the file at `ron_path` has changed, see [LookupCurveEguiEditor::enable_file_watch]
+    #[cfg(feature = "ron")]
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub pending_reload: Option<Arc<AtomicBool>>,
+
+    /// Screen positions of the knot handles during the last call to [LookupCurveEguiEditor::ui], in egui points
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub knot_handle_positions: Vec<Vec2>,
+
+    /// Fill the area between the curve and y = 0 with `fill_color`
+    pub fill_curve: bool,
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub fill_color: Color32,
+
+    /// Highlight knots where the curve is not C1 continuous, see [LookupCurve::continuity_violations]
+    pub highlight_discontinuities: bool,
+
+    /// Overlay the derivative dy/dx of the curve, scaled to fit the view and labeled on the right side
+    pub show_derivative_overlay: bool,
+
+    /// Texture painted behind the grid and curve, e.g. the frames of a sprite animation as a reference. See [LookupCurveEguiEditor::with_background_image].
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub background_image: Option<egui::TextureId>,
+    /// Bounding box `(min, max)` in curve space that the `background_image` is stretched to cover
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub background_image_rect: Option<(Vec2, Vec2)>,
+
+    /// Built-in sample playback, overriding the `sample` passed to [LookupCurveEguiEditor::ui] while set
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub playback: Option<PlaybackState>,
 }
 
 impl Default for LookupCurveEguiEditor {
@@ -29,6 +237,10 @@ impl Default for LookupCurveEguiEditor {
         Self {
             offset: Vec2::ZERO,
             scale: Vec2::new(1.0, 1.0),
+            min_scale: Vec2::splat(0.001),
+            max_scale: Vec2::splat(1000.0),
+            smooth_zoom: false,
+            zoom_target: None,
 
             grid_step_x: 0.1,
             grid_step_y: 0.1,
@@ -36,20 +248,144 @@ impl Default for LookupCurveEguiEditor {
             editor_size: Vec2::ZERO,
             hover_point: Vec2::ZERO,
 
+            auto_tangents: false,
+
+            tangent_visibility: TangentVisibility::default(),
+            tangent_display: TangentDisplay::default(),
+            selected_knots: Vec::new(),
+
+            pending_preset: None,
+            undo_history: Vec::new(),
+            unreal_import: None,
+            saved_tangents: HashMap::new(),
+
             #[cfg(feature = "ron")]
             ron_path: None,
+            #[cfg(feature = "ron")]
+            max_versions: 5,
+            #[cfg(feature = "ron")]
+            pending_reload: None,
+
+            knot_handle_positions: Vec::new(),
+
+            fill_curve: false,
+            fill_color: Color32::GREEN.gamma_multiply(0.25),
+
+            highlight_discontinuities: false,
+
+            show_derivative_overlay: false,
+
+            background_image: None,
+            background_image_rect: None,
+
+            playback: None,
         }
     }
 }
 
 impl LookupCurveEguiEditor {
+    /// Radius of the knot handles, in egui points
+    pub const KNOT_HANDLE_RADIUS: f32 = 8.0;
+    /// Number of samples written by "Export for Unreal", see [LookupCurve::to_unreal_curve_csv]
+    pub const UNREAL_CSV_RESOLUTION: u32 = 256;
+
     /// Constructs a [LookupCurveEguiEditor] with the supplied `path` as save path.
+    ///
+    /// If a sidecar file exists at [LookupCurveEguiEditor::state_path] for `path`, the editor state is loaded from it.
     #[cfg(feature = "ron")]
     pub fn with_save_path(path: String) -> Self {
+        let state_path = Self::state_path(&path);
+        let editor = if std::path::Path::new(&state_path).exists() {
+            Self::load_state(&state_path).unwrap_or_default()
+        } else {
+            Self::default()
+        };
+
         Self {
             ron_path: Some(path),
-            ..Default::default()
+            ..editor
+        }
+    }
+
+    /// Returns the path of the `.editor_prefs.ron` sidecar file used to persist the editor state for the curve at `ron_path`.
+    ///
+    /// For example `curves/jump.curve.ron` maps to `curves/jump.curve.editor_prefs.ron`.
+    #[cfg(feature = "ron")]
+    pub fn state_path(ron_path: &str) -> String {
+        format!(
+            "{}.editor_prefs.ron",
+            ron_path.strip_suffix(".ron").unwrap_or(ron_path)
+        )
+    }
+
+    /// Saves editor preferences like the viewport, grid and save path to a RON file at `path`.
+    ///
+    /// Use [LookupCurveEguiEditor::state_path] to have the state loaded automatically by [LookupCurveEguiEditor::with_save_path].
+    #[cfg(feature = "ron")]
+    pub fn save_state(&self, path: &str) -> Result<(), EditorStateError> {
+        let s = crate::to_pretty_ron(self)?;
+        std::fs::write(path, s.as_bytes())?;
+        Ok(())
+    }
+
+    /// Loads an editor previously saved using [LookupCurveEguiEditor::save_state].
+    ///
+    /// Transient state like the selection is reset, and missing preferences use their default values.
+    #[cfg(feature = "ron")]
+    pub fn load_state(path: &str) -> Result<Self, EditorStateError> {
+        let s = std::fs::read_to_string(path)?;
+        Ok(ron::from_str(&s)?)
+    }
+
+    /// Starts watching the file at `ron_path` for changes. When the file is modified, the curve will be reloaded during the next call to [LookupCurveEguiEditor::ui].
+    ///
+    /// Spawns a background thread polling the file modification time, which stops when the editor is dropped.
+    /// Does nothing if `ron_path` is not set or the file is already being watched.
+    #[cfg(feature = "ron")]
+    pub fn enable_file_watch(&mut self) {
+        let Some(path) = self.ron_path.clone() else {
+            return;
+        };
+        if self.pending_reload.is_some() {
+            return;
         }
+
+        let pending_reload = Arc::new(AtomicBool::new(false));
+        let flag = Arc::downgrade(&pending_reload);
+        self.pending_reload = Some(pending_reload);
+
+        std::thread::spawn(move || {
+            let modified = || std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+            let mut last_modified = modified();
+            loop {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+                let Some(flag) = flag.upgrade() else {
+                    break;
+                };
+                let current = modified();
+                if current != last_modified {
+                    last_modified = current;
+                    flag.store(true, Ordering::Relaxed);
+                }
+            }
+        });
+    }
+
+    /// Displays an editor for `curve` without having to store the [LookupCurveEguiEditor], the editor state is kept in egui memory keyed on `id` instead.
+    ///
+    /// The viewport is fitted to the curve the first time it is displayed.
+    ///
+    /// Returns `true` if the curve was changed during this update
+    pub fn new_inline(ui: &mut Ui, id: Id, curve: &mut LookupCurve) -> bool {
+        let editor = ui.memory_mut(|mem| {
+            mem.data
+                .get_temp_mut_or_insert_with(id, || {
+                    Arc::new(Mutex::new(LookupCurveEguiEditor::fitted_to_curve(curve)))
+                })
+                .clone()
+        });
+        let mut editor = editor.lock().unwrap();
+        editor.ui(ui, curve, None)
     }
 
     /// Constructs a [LookupCurveEguiEditor] with the viewport adjusted to fit the supplied [LookupCurve].
@@ -59,6 +395,15 @@ impl LookupCurveEguiEditor {
         editor
     }
 
+    /// Returns the editor with `texture` painted behind the grid and curve, stretched to cover the curve space bounding box from `min` to `max`.
+    ///
+    /// In Bevy, a `Handle<Image>` can be converted to a texture id with `EguiContexts::add_image`, see the `background_image` example.
+    pub fn with_background_image(mut self, texture: egui::TextureId, min: Vec2, max: Vec2) -> Self {
+        self.background_image = Some(texture);
+        self.background_image_rect = Some((min, max));
+        self
+    }
+
     /// Fits the editor viewport to the supplied [LookupCurve] by updating scale and offset.
     pub fn fit_to_curve(&mut self, curve: &LookupCurve) {
         let knots = curve.knots();
@@ -79,7 +424,89 @@ impl LookupCurveEguiEditor {
         let diff = max - min;
 
         self.offset = min - 0.2 * diff;
-        self.scale = diff * 1.4;
+        self.scale = (diff * 1.4).clamp(self.min_scale, self.max_scale);
+        self.zoom_target = None;
+    }
+
+    /// Fits the editor viewport to the bounding box of the selected knots, with some padding.
+    ///
+    /// Falls back to [LookupCurveEguiEditor::fit_to_curve] if no knots are selected.
+    pub fn fit_to_selection(&mut self, curve: &LookupCurve) {
+        let Some((min, max)) = curve
+            .knots()
+            .iter()
+            .filter(|knot| self.selected_knots.contains(&knot.id))
+            .map(|knot| (knot.position, knot.position))
+            .reduce(|(a_min, a_max), (b_min, b_max)| (a_min.min(b_min), a_max.max(b_max)))
+        else {
+            self.fit_to_curve(curve);
+            return;
+        };
+
+        // Pad by 20% of the size, or a fixed amount along axes where all selected knots line up
+        let size = max - min;
+        let padding = Vec2::select(size.cmpgt(Vec2::ZERO), 0.2 * size, Vec2::splat(0.5));
+        self.set_view_rect(
+            min.x - padding.x,
+            max.x + padding.x,
+            min.y - padding.y,
+            max.y + padding.y,
+        );
+    }
+
+    /// Sets the editor viewport to display the region between `x_min..x_max` and `y_min..y_max` in curve space.
+    ///
+    /// Useful for keeping the view of several editors in sync.
+    pub fn set_view_rect(&mut self, x_min: f32, x_max: f32, y_min: f32, y_max: f32) {
+        self.offset = Vec2::new(x_min, y_min);
+        self.scale = Vec2::new(x_max - x_min, y_max - y_min);
+        self.zoom_target = None;
+    }
+
+    /// Resets the editor viewport, fitting it to the supplied [LookupCurve] if it has any knots.
+    pub fn reset_view(&mut self, curve: &LookupCurve) {
+        if curve.knots().is_empty() {
+            self.offset = Vec2::ZERO;
+            self.scale = Vec2::ONE;
+            self.zoom_target = None;
+        } else {
+            self.fit_to_curve(curve);
+        }
+    }
+
+    /// Moves the scale a step towards `target` for [LookupCurveEguiEditor::smooth_zoom]. Returns `true` while the target has not been reached.
+    fn step_smooth_zoom(&mut self, target: Vec2) -> bool {
+        const SMOOTHING: f32 = 0.3;
+
+        self.scale = self.scale.lerp(target, SMOOTHING);
+        if ((self.scale - target) / target).abs().max_element() < 1e-3 {
+            self.scale = target;
+            self.zoom_target = None;
+            return false;
+        }
+        true
+    }
+
+    /// Changes the interpolation of knot `i` using [LookupCurve::knot_with_interpolation], saving the tangents in [LookupCurveEguiEditor::saved_tangents] when switching away from [KnotInterpolation::Cubic]
+    fn knot_with_interpolation(
+        &mut self,
+        curve: &LookupCurve,
+        i: usize,
+        interpolation: KnotInterpolation,
+    ) -> Knot {
+        let knot = &curve.knots()[i];
+        let was_cubic = matches!(knot.interpolation, KnotInterpolation::Cubic);
+        let is_cubic = matches!(interpolation, KnotInterpolation::Cubic);
+        let saved_tangents = if was_cubic && !is_cubic {
+            self.saved_tangents
+                .insert(knot.id, (knot.left_tangent, knot.right_tangent));
+            None
+        } else if !was_cubic && is_cubic {
+            self.saved_tangents.remove(&knot.id)
+        } else {
+            None
+        };
+        curve.knot_with_interpolation(i, interpolation, saved_tangents)
     }
 
     // TODO : Rename these functions and make them clearer
@@ -139,24 +566,279 @@ impl LookupCurveEguiEditor {
                 self.fit_to_curve(curve);
             }
 
+            if ui.button("Reset view").clicked() {
+                self.reset_view(curve);
+            }
+
+            if ui.button("Zoom to selection").clicked() {
+                self.fit_to_selection(curve);
+            }
+
+            ui.menu_button("Preset", |ui| {
+                for preset in CurvePreset::ALL {
+                    if ui.button(preset.name()).clicked() {
+                        self.pending_preset = Some(preset);
+                        ui.close_menu();
+                    }
+                }
+            });
+
+            if ui.button("Flip Y").clicked() {
+                self.undo_history.push(UndoStep::new(curve));
+                *curve = curve.invert_output();
+                changed = true;
+            }
+
+            if ui
+                .add_enabled(!self.undo_history.is_empty(), egui::Button::new("Undo"))
+                .clicked()
+            {
+                if let Some(step) = self.undo_history.pop() {
+                    step.restore(curve);
+                    changed = true;
+                }
+            }
+
+            ui.checkbox(&mut self.auto_tangents, "Auto-update tangents");
+            ui.checkbox(&mut self.show_derivative_overlay, "Show derivative");
+
+            let (display_text, next_display) = match self.tangent_display {
+                TangentDisplay::Slope => ("Slope: rise/run", TangentDisplay::Degrees),
+                TangentDisplay::Degrees => ("Slope: degrees", TangentDisplay::Slope),
+            };
+            if ui.button(display_text).clicked() {
+                self.tangent_display = next_display;
+            }
+
+            egui::ComboBox::from_id_salt("tangent_visibility")
+                .selected_text(format!("Tangents: {:?}", self.tangent_visibility))
+                .show_ui(ui, |ui| {
+                    for visibility in [
+                        TangentVisibility::Always,
+                        TangentVisibility::OnSelect,
+                        TangentVisibility::Never,
+                    ] {
+                        ui.selectable_value(
+                            &mut self.tangent_visibility,
+                            visibility,
+                            format!("{:?}", visibility),
+                        );
+                    }
+                });
+
+            let mut clamped = curve.output_clamp.is_some();
+            if ui.checkbox(&mut clamped, "Clamp output").changed() {
+                curve.output_clamp = clamped.then_some((0.0, 1.0));
+                changed = true;
+            }
+            if let Some((min, max)) = &mut curve.output_clamp {
+                ui.label("min:");
+                changed |= ui.add(egui::DragValue::new(min).speed(0.001)).changed();
+                ui.label("max:");
+                changed |= ui.add(egui::DragValue::new(max).speed(0.001)).changed();
+            }
+
+            let mut tension = curve.tension;
+            if ui
+                .add(egui::Slider::new(&mut tension, 0.0..=1.0).text("Tension"))
+                .changed()
+            {
+                curve.set_tension(tension);
+                changed = true;
+            }
+
+            let playing = self.playback.is_some_and(|playback| !playback.paused);
+            if ui.button(if playing { "Pause" } else { "Play" }).clicked() {
+                let playback = self.playback.get_or_insert_with(Default::default);
+                playback.paused = playing;
+            }
+            if let Some(playback) = &mut self.playback {
+                ui.add(
+                    egui::DragValue::new(&mut playback.speed)
+                        .speed(0.01)
+                        .prefix("speed: "),
+                );
+                egui::ComboBox::from_id_salt("playback_loop_mode")
+                    .selected_text(format!("{:?}", playback.loop_mode))
+                    .show_ui(ui, |ui| {
+                        for loop_mode in [
+                            ExtrapolationMode::Clamp,
+                            ExtrapolationMode::Repeat,
+                            ExtrapolationMode::PingPong,
+                        ] {
+                            ui.selectable_value(
+                                &mut playback.loop_mode,
+                                loop_mode,
+                                format!("{:?}", loop_mode),
+                            );
+                        }
+                    });
+                if ui.button("Stop").clicked() {
+                    self.playback = None;
+                }
+            }
+
+            if ui
+                .button("Copy as Rust")
+                .on_hover_text("Copy a Rust expression constructing the curve to the clipboard")
+                .clicked()
+            {
+                ui.ctx().copy_text(curve.to_rust_code());
+            }
+
+            if ui
+                .button("Export for Unreal")
+                .on_hover_text(
+                    "Copy the curve as an Unreal Engine curve table CSV to the clipboard",
+                )
+                .clicked()
+            {
+                ui.ctx()
+                    .copy_text(curve.to_unreal_curve_csv(Self::UNREAL_CSV_RESOLUTION));
+            }
+            if ui.button("Import from Unreal").clicked() {
+                self.unreal_import = Some(String::new());
+            }
+
+            ui.label("?").on_hover_text(
+                "Drag the background to pan, scroll to zoom\n\
+                 Ctrl+0 or double-click the background to reset the view\n\
+                 Z zooms to the selected knots\n\
+                 Right-click the background to add a knot\n\
+                 Shift-click knots to select multiple\n\
+                 Hold Alt while dragging a weighted tangent to only change its weight",
+            );
+
             ui.label(format!(
                 "x = {}, y = {}",
                 self.hover_point.x, self.hover_point.y
             ));
         });
 
+        if let Some(playback) = &mut self.playback {
+            if !playback.paused {
+                playback.t += playback.speed * ui.input(|i| i.stable_dt);
+                ui.ctx()
+                    .request_repaint_after(std::time::Duration::from_secs_f32(1.0 / 60.0));
+            }
+        }
+        let sample = match &self.playback {
+            Some(playback) => Some(playback.sample_x(curve.domain())),
+            None => sample,
+        };
+
+        if let Some(preset) = self.pending_preset {
+            let mut open = true;
+            egui::Window::new("Replace current curve?")
+                .id(ui.id().with("replace_with_preset"))
+                .collapsible(false)
+                .resizable(false)
+                .show(ui.ctx(), |ui| {
+                    ui.label(format!(
+                        "The knots of the curve will be replaced with the \"{}\" preset.",
+                        preset.name()
+                    ));
+                    ui.horizontal(|ui| {
+                        if ui.button("Replace").clicked() {
+                            self.undo_history.push(UndoStep::new(curve));
+                            curve.knots = preset.knots();
+                            changed = true;
+                            open = false;
+                        }
+                        if ui.button("Cancel").clicked() {
+                            open = false;
+                        }
+                    });
+                });
+            if !open {
+                self.pending_preset = None;
+            }
+        }
+
+        if let Some(csv) = &mut self.unreal_import {
+            let mut open = true;
+            egui::Window::new("Import from Unreal")
+                .id(ui.id().with("import_from_unreal"))
+                .collapsible(false)
+                .show(ui.ctx(), |ui| {
+                    ui.label("Paste an Unreal Engine curve table CSV. The knots of the curve will be replaced with its first row.");
+                    egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                        ui.add(egui::TextEdit::multiline(csv).code_editor());
+                    });
+
+                    let parsed = LookupCurve::from_unreal_curve_csv(csv);
+                    if let (Err(e), false) = (&parsed, csv.trim().is_empty()) {
+                        ui.colored_label(ui.visuals().error_fg_color, e.to_string());
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(parsed.is_ok(), egui::Button::new("Import")).clicked() {
+                            if let Ok(imported) = parsed {
+                                self.undo_history.push(UndoStep::new(curve));
+                                curve.knots = imported.knots;
+                                changed = true;
+                            }
+                            open = false;
+                        }
+                        if ui.button("Cancel").clicked() {
+                            open = false;
+                        }
+                    });
+                });
+            if !open {
+                self.unreal_import = None;
+            }
+        }
+
         #[cfg(feature = "ron")]
-        if self.ron_path.is_some() && ui.button("Save").clicked() {
-            if let Err(e) = curve.save_to_file(self.ron_path.as_ref().unwrap().as_str()) {
-                #[cfg(feature = "bevy_app")]
-                bevy_log::error!("Failed to save curve {}", e);
-                #[cfg(not(feature = "bevy_app"))]
-                println!("Failed to save curve {}", e);
-            } else {
-                #[cfg(feature = "bevy_app")]
-                bevy_log::info!("Curve saved successfully.");
-                #[cfg(not(feature = "bevy_app"))]
-                println!("Curve saved successfully.");
+        if let (Some(ron_path), Some(pending_reload)) = (&self.ron_path, &self.pending_reload) {
+            if pending_reload.swap(false, Ordering::Relaxed) {
+                match LookupCurve::load_from_file(ron_path.as_str()) {
+                    Ok(loaded) => {
+                        *curve = loaded;
+                        changed = true;
+                    }
+                    Err(e) => {
+                        #[cfg(feature = "bevy_app")]
+                        bevy_log::error!("Failed to reload curve {}", e);
+                        #[cfg(not(feature = "bevy_app"))]
+                        println!("Failed to reload curve {}", e);
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "ron")]
+        if let Some(ron_path) = &self.ron_path {
+            let result = ui
+                .horizontal(|ui| {
+                    if ui.button("Save").clicked() {
+                        Some(curve.save_to_file(ron_path.as_str()))
+                    } else if ui
+                        .button("Save with backup")
+                        .on_hover_text(format!(
+                            "Keeps up to {} previous versions as {}.1, {}.2, ...",
+                            self.max_versions, ron_path, ron_path
+                        ))
+                        .clicked()
+                    {
+                        Some(curve.save_to_file_versioned(ron_path.as_str(), self.max_versions))
+                    } else {
+                        None
+                    }
+                })
+                .inner;
+            if let Some(result) = result {
+                if let Err(e) = result {
+                    #[cfg(feature = "bevy_app")]
+                    bevy_log::error!("Failed to save curve {}", e);
+                    #[cfg(not(feature = "bevy_app"))]
+                    println!("Failed to save curve {}", e);
+                } else {
+                    #[cfg(feature = "bevy_app")]
+                    bevy_log::info!("Curve saved successfully.");
+                    #[cfg(not(feature = "bevy_app"))]
+                    println!("Curve saved successfully.");
+                }
             }
         }
 
@@ -186,7 +868,14 @@ impl LookupCurveEguiEditor {
                 ui.input(|input| {
                     let scroll_delta = input.raw_scroll_delta.y;
                     if scroll_delta != 0.0 {
-                        self.scale *= 1.0 + -scroll_delta * 0.001;
+                        let target = (self.zoom_target.unwrap_or(self.scale)
+                            * (1.0 + -scroll_delta * 0.001))
+                            .clamp(self.min_scale, self.max_scale);
+                        if self.smooth_zoom {
+                            self.zoom_target = Some(target);
+                        } else {
+                            self.scale = target;
+                        }
                         // TODO: adjust offset accordingly
                     }
                 });
@@ -194,11 +883,38 @@ impl LookupCurveEguiEditor {
                 self.hover_point = Vec2::ZERO;
             }
 
+            if let Some(target) = self.zoom_target {
+                if self.step_smooth_zoom(target) {
+                    ui.ctx().request_repaint();
+                }
+            }
+
             // Panning
             if response.dragged() || response.dragged_by(egui::PointerButton::Middle) {
                 self.offset -= self.canvas_to_curve_vec(response.drag_delta());
             }
 
+            // Clicking the background clears the selection
+            if response.clicked() {
+                self.selected_knots.clear();
+            }
+
+            // Ctrl+0 or double-clicking the background resets the view
+            if response.double_clicked()
+                || (response.hovered()
+                    && ui.input(|i| i.key_pressed(egui::Key::Num0) && i.modifiers.ctrl))
+            {
+                self.reset_view(curve);
+            }
+
+            // Z zooms to the selected knots
+            if response.hovered()
+                && !self.selected_knots.is_empty()
+                && ui.input(|i| i.key_pressed(egui::Key::Z) && i.modifiers.is_none())
+            {
+                self.fit_to_selection(curve);
+            }
+
             response.context_menu(|ui| {
                 let menu_pos = ui.min_rect().left_top(); // hacky and not entirely correct
                 if ui.button("Add knot").clicked() {
@@ -211,17 +927,56 @@ impl LookupCurveEguiEditor {
                 }
             });
 
+            if let (Some(texture), Some((min, max))) =
+                (self.background_image, self.background_image_rect)
+            {
+                painter.image(
+                    texture,
+                    Rect::from_two_pos(
+                        to_screen.transform_pos(self.curve_to_canvas(min)),
+                        to_screen.transform_pos(self.curve_to_canvas(max)),
+                    ),
+                    Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
+                    Color32::WHITE,
+                );
+            }
+
             self.paint_grid(&painter, &to_screen);
 
+            // Output clamp bounds
+            if let Some((min, max)) = curve.output_clamp {
+                for y in [min, max] {
+                    painter.add(Shape::dashed_line(
+                        &[
+                            to_screen
+                                .transform_pos(self.curve_to_canvas(Vec2::new(self.offset.x, y))),
+                            to_screen.transform_pos(
+                                self.curve_to_canvas(Vec2::new(self.offset.x + self.scale.x, y)),
+                            ),
+                        ],
+                        Stroke::new(1.0, Color32::YELLOW),
+                        6.0,
+                        4.0,
+                    ));
+                }
+            }
+
             // Draw the curve
             let curve_stroke = Stroke {
                 color: Color32::GREEN,
                 width: 2.0,
             };
 
+            // Segments overshooting their knots are drawn in a warning color
+            let overshoot_segments = curve.overshoot_segments();
+            let overshoot_stroke = Stroke {
+                color: Color32::ORANGE,
+                ..curve_stroke
+            };
+
             // TODO: Only knots inside viewport
             let mut prev_knot: Option<&Knot> = None;
-            for knot in curve.knots().iter() {
+            for (i, knot) in curve.knots().iter().enumerate() {
                 if let Some(prev_knot) = prev_knot {
                     match prev_knot.interpolation {
                         KnotInterpolation::Constant => {
@@ -248,48 +1003,149 @@ impl LookupCurveEguiEditor {
                                 curve_stroke,
                             ));
                         }
-                        KnotInterpolation::Cubic => {
-                            painter.add(CubicBezierShape::from_points_stroke(
-                                prev_knot
-                                    .compute_bezier_to(knot)
+                        KnotInterpolation::BSpline => {
+                            let bezier = curve.segment_bezier(i - 1);
+
+                            // Control polygon, along with the points where the curve joins its neighbouring segments
+                            painter.add(Shape::dashed_line(
+                                &[prev_knot.position, knot.position]
                                     .map(|p| to_screen.transform_pos(self.curve_to_canvas(p))),
+                                Stroke::new(1.0, Color32::GRAY),
+                                4.0,
+                                2.0,
+                            ));
+                            for p in [bezier[0], bezier[3]] {
+                                painter.add(Shape::circle_filled(
+                                    to_screen.transform_pos(self.curve_to_canvas(p)),
+                                    2.5,
+                                    Color32::GREEN,
+                                ));
+                            }
+
+                            painter.add(CubicBezierShape::from_points_stroke(
+                                bezier.map(|p| to_screen.transform_pos(self.curve_to_canvas(p))),
                                 false,
                                 Color32::TRANSPARENT,
                                 curve_stroke,
                             ));
                         }
+                        KnotInterpolation::Cubic
+                        | KnotInterpolation::Cardinal
+                        | KnotInterpolation::HermiteRaw { .. } => {
+                            painter.add(CubicBezierShape::from_points_stroke(
+                                curve
+                                    .segment_bezier(i - 1)
+                                    .map(|p| to_screen.transform_pos(self.curve_to_canvas(p))),
+                                false,
+                                Color32::TRANSPARENT,
+                                if overshoot_segments.contains(&(i - 1)) {
+                                    overshoot_stroke
+                                } else {
+                                    curve_stroke
+                                },
+                            ));
+                        }
                     }
                 }
 
                 prev_knot = Some(knot);
             }
 
+            if self.fill_curve {
+                self.paint_fill(&painter, &to_screen, curve);
+            }
+
+            if self.show_derivative_overlay {
+                self.paint_derivative_overlay(&painter, &to_screen, curve);
+            }
+
+            let discontinuous_knots: Vec<usize> = if self.highlight_discontinuities {
+                curve
+                    .continuity_violations(1e-3)
+                    .iter()
+                    .map(|violation| violation.knot_index)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
             // Handles
-            let knot_radius = 8.0;
+            let knot_radius = Self::KNOT_HANDLE_RADIUS;
             let mut modified_knot = None;
             let mut deleted_knot_index = None;
+            self.knot_handle_positions.clear();
             for (i, knot) in curve.knots().iter().enumerate() {
                 let prev_knot = curve.prev_knot(i);
                 let next_knot = curve.next_knot(i);
 
                 let point_in_screen = to_screen.transform_pos(self.curve_to_canvas(knot.position));
+                self.knot_handle_positions
+                    .push(Vec2::new(point_in_screen.x, point_in_screen.y));
                 let interact_rect =
                     Rect::from_center_size(point_in_screen, emath::Vec2::splat(2.0 * knot_radius));
                 let interact_id = response.id.with(knot.id);
-                let interact_response = ui.interact(interact_rect, interact_id, Sense::drag());
+                let interact_response =
+                    ui.interact(interact_rect, interact_id, Sense::click_and_drag());
+
+                // Selection, hold shift to select multiple knots
+                if interact_response.clicked() || interact_response.drag_started() {
+                    if ui.input(|input| input.modifiers.shift) {
+                        if let Some(index) =
+                            self.selected_knots.iter().position(|id| *id == knot.id)
+                        {
+                            self.selected_knots.remove(index);
+                        } else {
+                            self.selected_knots.push(knot.id);
+                        }
+                    } else if !self.selected_knots.contains(&knot.id) {
+                        self.selected_knots.clear();
+                        self.selected_knots.push(knot.id);
+                    }
+                }
 
-                if interact_response.dragged_by(egui::PointerButton::Primary) {
+                if !knot.locked
+                    && !knot.anchor
+                    && interact_response.dragged_by(egui::PointerButton::Primary)
+                {
                     modified_knot = Some((
                         i,
                         Knot {
                             position: knot.position
                                 + self.canvas_to_curve_vec(interact_response.drag_delta()),
-                            ..*knot
+                            ..knot.clone()
                         },
                     ));
                 }
 
                 interact_response.context_menu(|ui| {
+                    let mut locked = knot.locked;
+                    if ui.checkbox(&mut locked, "Locked").changed() {
+                        modified_knot = Some((
+                            i,
+                            Knot {
+                                locked,
+                                ..knot.clone()
+                            },
+                        ));
+                    }
+                    if knot.locked {
+                        ui.disable();
+                    }
+                    let mut anchor = knot.anchor;
+                    if ui
+                        .checkbox(&mut anchor, "Anchor")
+                        .on_hover_text("Prevent the knot from being moved in the editor")
+                        .changed()
+                    {
+                        modified_knot = Some((
+                            i,
+                            Knot {
+                                anchor,
+                                ..knot.clone()
+                            },
+                        ));
+                    }
+
                     ui.label("Interpolation");
                     if ui
                         .radio(
@@ -300,10 +1156,7 @@ impl LookupCurveEguiEditor {
                     {
                         modified_knot = Some((
                             i,
-                            Knot {
-                                interpolation: KnotInterpolation::Constant,
-                                ..*knot
-                            },
+                            self.knot_with_interpolation(curve, i, KnotInterpolation::Constant),
                         ));
                         ui.close_menu();
                     }
@@ -316,10 +1169,7 @@ impl LookupCurveEguiEditor {
                     {
                         modified_knot = Some((
                             i,
-                            Knot {
-                                interpolation: KnotInterpolation::Linear,
-                                ..*knot
-                            },
+                            self.knot_with_interpolation(curve, i, KnotInterpolation::Linear),
                         ));
                         ui.close_menu();
                     }
@@ -332,16 +1182,96 @@ impl LookupCurveEguiEditor {
                     {
                         modified_knot = Some((
                             i,
-                            Knot {
-                                interpolation: KnotInterpolation::Cubic,
-                                ..*knot
-                            },
+                            self.knot_with_interpolation(curve, i, KnotInterpolation::Cubic),
+                        ));
+                        ui.close_menu();
+                    }
+                    if ui
+                        .radio(
+                            matches!(knot.interpolation, KnotInterpolation::Cardinal),
+                            "Cardinal",
+                        )
+                        .clicked()
+                    {
+                        modified_knot = Some((
+                            i,
+                            self.knot_with_interpolation(curve, i, KnotInterpolation::Cardinal),
+                        ));
+                        ui.close_menu();
+                    }
+                    if ui
+                        .radio(
+                            matches!(knot.interpolation, KnotInterpolation::BSpline),
+                            "B-spline",
+                        )
+                        .clicked()
+                    {
+                        modified_knot = Some((
+                            i,
+                            self.knot_with_interpolation(curve, i, KnotInterpolation::BSpline),
                         ));
                         ui.close_menu();
                     }
+                    if ui
+                        .radio(
+                            matches!(knot.interpolation, KnotInterpolation::HermiteRaw { .. }),
+                            "Hermite (raw tangents)",
+                        )
+                        .clicked()
+                    {
+                        let width = |a: Option<&Knot>, b: Option<&Knot>| match (a, b) {
+                            (Some(a), Some(b)) => b.position.x - a.position.x,
+                            _ => 0.0,
+                        };
+                        let in_width = width(curve.prev_knot(i), Some(knot));
+                        let out_width = width(Some(knot), curve.next_knot(i));
+                        let raw = knot.to_hermite_raw(
+                            if in_width > 0.0 { in_width } else { out_width },
+                            if out_width > 0.0 { out_width } else { in_width },
+                        );
+                        modified_knot =
+                            Some((i, self.knot_with_interpolation(curve, i, raw.interpolation)));
+                        ui.close_menu();
+                    }
+
+                    if let KnotInterpolation::HermiteRaw {
+                        mut tangent_in,
+                        mut tangent_out,
+                    } = knot.interpolation
+                    {
+                        let mut tangent_changed = false;
+                        for (label, tangent) in
+                            [("In:", &mut tangent_in), ("Out:", &mut tangent_out)]
+                        {
+                            ui.horizontal(|ui| {
+                                ui.label(label);
+                                tangent_changed |= ui
+                                    .add(egui::DragValue::new(&mut tangent.x).speed(0.01))
+                                    .changed();
+                                tangent_changed |= ui
+                                    .add(egui::DragValue::new(&mut tangent.y).speed(0.01))
+                                    .changed();
+                            });
+                        }
+                        if tangent_changed {
+                            modified_knot = Some((
+                                i,
+                                Knot {
+                                    interpolation: KnotInterpolation::HermiteRaw {
+                                        tangent_in,
+                                        tangent_out,
+                                    },
+                                    ..knot.clone()
+                                },
+                            ));
+                        }
+                    }
 
                     ui.label("Position");
                     ui.horizontal(|ui| {
+                        if knot.anchor {
+                            ui.disable();
+                        }
                         ui.label("x:");
                         ui.add(
                             egui::DragValue::from_get_set(|v| match v {
@@ -350,7 +1280,7 @@ impl LookupCurveEguiEditor {
                                         i,
                                         Knot {
                                             position: Vec2::new(v as f32, knot.position.y),
-                                            ..*knot
+                                            ..knot.clone()
                                         },
                                     ));
                                     v
@@ -367,7 +1297,7 @@ impl LookupCurveEguiEditor {
                                         i,
                                         Knot {
                                             position: Vec2::new(knot.position.x, v as f32),
-                                            ..*knot
+                                            ..knot.clone()
                                         },
                                     ));
                                     v
@@ -378,7 +1308,40 @@ impl LookupCurveEguiEditor {
                         );
                     });
 
+                    ui.label("Annotation");
+                    let mut annotation = knot.annotation.clone().unwrap_or_default();
+                    if ui.text_edit_singleline(&mut annotation).changed() {
+                        modified_knot = Some((
+                            i,
+                            Knot {
+                                annotation: (!annotation.is_empty()).then_some(annotation),
+                                ..knot.clone()
+                            },
+                        ));
+                    }
+
                     ui.label("Actions");
+                    if let Some(prev) = curve.prev_knot(i) {
+                        if ui.button("Match tangent to left neighbor").clicked() {
+                            modified_knot = Some((
+                                i,
+                                knot.with_tangent_slope(
+                                    TangentSide::Right,
+                                    prev.right_tangent.slope,
+                                ),
+                            ));
+                            ui.close_menu();
+                        }
+                    }
+                    if let Some(next) = curve.next_knot(i) {
+                        if ui.button("Match tangent to right neighbor").clicked() {
+                            modified_knot = Some((
+                                i,
+                                knot.with_tangent_slope(TangentSide::Left, next.left_tangent.slope),
+                            ));
+                            ui.close_menu();
+                        }
+                    }
                     if ui.button("Delete knot").clicked() {
                         deleted_knot_index = Some(i);
                         ui.close_menu();
@@ -388,11 +1351,79 @@ impl LookupCurveEguiEditor {
                 painter.add(Shape::circle_filled(
                     to_screen.transform_pos(self.curve_to_canvas(knot.position)),
                     3.0,
-                    Color32::LIGHT_GREEN,
+                    if knot.locked {
+                        Color32::ORANGE
+                    } else {
+                        Color32::LIGHT_GREEN
+                    },
                 ));
 
+                if knot.anchor {
+                    painter.text(
+                        point_in_screen + emath::Vec2::new(0.0, -knot_radius),
+                        egui::Align2::CENTER_BOTTOM,
+                        "📌",
+                        egui::FontId::proportional(12.0),
+                        Color32::WHITE,
+                    );
+                }
+
+                if let Some(annotation) = &knot.annotation {
+                    painter.text(
+                        point_in_screen + emath::Vec2::new(6.0, -6.0),
+                        egui::Align2::LEFT_BOTTOM,
+                        annotation,
+                        egui::FontId::default(),
+                        Color32::LIGHT_GRAY,
+                    );
+                }
+
+                if discontinuous_knots.contains(&i) {
+                    painter.add(Shape::circle_stroke(
+                        point_in_screen,
+                        7.0,
+                        Stroke::new(1.5, Color32::ORANGE),
+                    ));
+                }
+
+                let selected = self.selected_knots.contains(&knot.id);
+                if selected {
+                    painter.add(Shape::circle_stroke(
+                        to_screen.transform_pos(self.curve_to_canvas(knot.position)),
+                        5.0,
+                        Stroke::new(1.0, Color32::WHITE),
+                    ));
+                }
+
+                let show_tangents = match self.tangent_visibility {
+                    TangentVisibility::Always => true,
+                    TangentVisibility::OnSelect => selected,
+                    TangentVisibility::Never => false,
+                };
+
+                // bezier control polygons of the adjacent cubic segments
+                if selected {
+                    let segments = [
+                        prev_knot
+                            .filter(|k| matches!(k.interpolation, KnotInterpolation::Cubic))
+                            .map(|k| k.compute_bezier_to(knot)),
+                        next_knot
+                            .filter(|_| matches!(knot.interpolation, KnotInterpolation::Cubic))
+                            .map(|k| knot.compute_bezier_to(k)),
+                    ];
+                    for bezier in segments.into_iter().flatten() {
+                        painter.add(Shape::line(
+                            bezier
+                                .map(|p| to_screen.transform_pos(self.curve_to_canvas(p)))
+                                .to_vec(),
+                            Stroke::new(1.0, Color32::DARK_BLUE),
+                        ));
+                    }
+                }
+
                 // tangents
                 const UNWEIGHTED_TANGENT_LEN: f32 = 60.;
+                let tangent_display = self.tangent_display;
                 let mut tangent_ui = |side: TangentSide| {
                     let (tangent, bezier, dir) = match side {
                         TangentSide::Left => (
@@ -429,7 +1460,7 @@ impl LookupCurveEguiEditor {
                     let interact_id = interact_id.with(side);
                     let interact_response = ui.interact(interact_rect, interact_id, Sense::drag());
 
-                    if interact_response.dragged_by(egui::PointerButton::Primary) {
+                    if !knot.locked && interact_response.dragged_by(egui::PointerButton::Primary) {
                         let mut c = self.canvas_to_curve(
                             to_canvas
                                 .transform_pos(interact_response.interact_pointer_pos().unwrap()),
@@ -445,6 +1476,13 @@ impl LookupCurveEguiEditor {
                         let (new_slope, new_weight) =
                             slope_weight_from_bezier(bezier[0], bezier[3], endpoint, c, dir);
 
+                        // Holding Alt only changes the weight, keeping the slope
+                        let new_slope = if ui.input(|i| i.modifiers.alt) {
+                            tangent.slope
+                        } else {
+                            new_slope
+                        };
+
                         let mut knot = knot.with_tangent_slope(side, new_slope);
                         if tangent.weight.is_some() {
                             knot = knot.with_tangent_weight(side, Some(new_weight));
@@ -471,19 +1509,46 @@ impl LookupCurveEguiEditor {
                                 Some((i, knot.with_tangent_mode(side, TangentMode::Aligned)));
                             ui.close_menu();
                         }
+                        if ui
+                            .button("Reset to auto")
+                            .on_hover_text("Use the Catmull-Rom slope from the neighbouring knots")
+                            .clicked()
+                        {
+                            modified_knot = Some((
+                                i,
+                                knot.with_tangent_slope(side, curve.catmull_rom_slope(i)),
+                            ));
+                            ui.close_menu();
+                        }
 
                         ui.label("Slope:");
-                        ui.add(
-                            egui::DragValue::from_get_set(|v| match v {
-                                Some(v) => {
-                                    modified_knot =
-                                        Some((i, knot.with_tangent_slope(side, v as f32)));
-                                    v
-                                }
-                                _ => tangent.slope as f64,
-                            })
-                            .speed(0.001),
-                        );
+                        match tangent_display {
+                            TangentDisplay::Slope => ui.add(
+                                egui::DragValue::from_get_set(|v| match v {
+                                    Some(v) => {
+                                        modified_knot =
+                                            Some((i, knot.with_tangent_slope(side, v as f32)));
+                                        v
+                                    }
+                                    _ => tangent.slope as f64,
+                                })
+                                .speed(0.001),
+                            ),
+                            TangentDisplay::Degrees => ui.add(
+                                egui::DragValue::from_get_set(|v| match v {
+                                    Some(v) => {
+                                        let slope = degrees_to_slope(v as f32);
+                                        modified_knot =
+                                            Some((i, knot.with_tangent_slope(side, slope)));
+                                        v
+                                    }
+                                    _ => slope_to_degrees(tangent.slope) as f64,
+                                })
+                                .range(-90.0..=90.0)
+                                .suffix("°")
+                                .speed(0.1),
+                            ),
+                        };
 
                         let mut weighted = tangent.weight.is_some();
                         if ui.checkbox(&mut weighted, "Weighted").changed() {
@@ -533,12 +1598,16 @@ impl LookupCurveEguiEditor {
                 };
 
                 // right tangent
-                if matches!(knot.interpolation, KnotInterpolation::Cubic) && next_knot.is_some() {
+                if show_tangents
+                    && matches!(knot.interpolation, KnotInterpolation::Cubic)
+                    && next_knot.is_some()
+                {
                     tangent_ui(TangentSide::Right);
                 }
 
                 // left tangent
-                if prev_knot.is_some()
+                if show_tangents
+                    && prev_knot.is_some()
                     && matches!(prev_knot.unwrap().interpolation, KnotInterpolation::Cubic)
                 {
                     tangent_ui(TangentSide::Left);
@@ -547,7 +1616,11 @@ impl LookupCurveEguiEditor {
 
             // Apply modifications
             if let Some((i, knot)) = modified_knot {
-                curve.modify_knot(i, knot);
+                if self.auto_tangents && curve.knots()[i].position != knot.position {
+                    curve.modify_knot_smooth(i, knot);
+                } else {
+                    curve.modify_knot(i, knot);
+                }
                 changed = true;
             }
             if let Some(i) = deleted_knot_index {
@@ -557,10 +1630,41 @@ impl LookupCurveEguiEditor {
 
             // Sample to visualize and test find_y_given_x
             if let Some(sample) = sample {
+                let value = curve.lookup(sample);
+                let point = self.curve_to_canvas(Vec2::new(sample, value));
+
+                // Project the sample onto the axes
+                let sample_stroke = Stroke::new(1.0, Color32::RED.gamma_multiply(0.6));
+                let x_axis_point = Pos2::new(point.x, self.editor_size.y);
+                let y_axis_point = Pos2::new(0.0, point.y);
+                for from in [x_axis_point, y_axis_point] {
+                    painter.add(Shape::dashed_line(
+                        &[
+                            to_screen.transform_pos(from),
+                            to_screen.transform_pos(point),
+                        ],
+                        sample_stroke,
+                        4.0,
+                        2.0,
+                    ));
+                }
+                painter.text(
+                    to_screen.transform_pos(x_axis_point + emath::Vec2::new(3.0, -20.0)),
+                    egui::Align2::LEFT_BOTTOM,
+                    format!("{:.3}", sample),
+                    egui::FontId::default(),
+                    Color32::RED,
+                );
+                painter.text(
+                    to_screen.transform_pos(y_axis_point + emath::Vec2::new(40.0, -3.0)),
+                    egui::Align2::LEFT_BOTTOM,
+                    format!("{:.3}", value),
+                    egui::FontId::default(),
+                    Color32::RED,
+                );
+
                 painter.add(Shape::circle_filled(
-                    to_screen.transform_pos(
-                        self.curve_to_canvas(Vec2::new(sample, curve.lookup(sample))),
-                    ),
+                    to_screen.transform_pos(point),
                     3.0,
                     Color32::RED,
                 ));
@@ -570,9 +1674,125 @@ impl LookupCurveEguiEditor {
         changed
     }
 
+    /// Draws the derivative of the curve over the visible x-range, normalized by its largest magnitude so that it spans
+    /// most of the view height with zero in the middle, along with labels for the scale on the right side
+    fn paint_derivative_overlay(
+        &self,
+        painter: &Painter,
+        to_screen: &emath::RectTransform,
+        curve: &LookupCurve,
+    ) {
+        const COLOR: Color32 = Color32::ORANGE;
+        const HEADROOM: f32 = 0.9;
+
+        // One sample per point of width
+        let sample_count = (self.editor_size.x as usize).max(2);
+        let mut cache = LookupCache::new();
+        let samples: Vec<(f32, f32)> = (0..sample_count)
+            .map(|i| {
+                let x = self.offset.x + self.scale.x * i as f32 / (sample_count - 1) as f32;
+                (x, curve.lookup_with_derivative_cached(x, &mut cache).1)
+            })
+            .collect();
+
+        let max_magnitude = samples.iter().fold(0.0_f32, |max, (_, d)| max.max(d.abs()));
+        let max_magnitude = if max_magnitude > 0.0 {
+            max_magnitude
+        } else {
+            1.0
+        };
+        let center_y = self.offset.y + self.scale.y * 0.5;
+        let half_height = self.scale.y * 0.5 * HEADROOM;
+        let to_view_y = |d: f32| center_y + d / max_magnitude * half_height;
+
+        painter.add(Shape::dashed_line(
+            &samples
+                .iter()
+                .map(|(x, d)| {
+                    to_screen.transform_pos(self.curve_to_canvas(Vec2::new(*x, to_view_y(*d))))
+                })
+                .collect::<Vec<_>>(),
+            Stroke::new(1.5, COLOR),
+            6.0,
+            4.0,
+        ));
+
+        let precision = grid_label_precision(max_magnitude / 10.0);
+        for d in [max_magnitude, 0.0, -max_magnitude] {
+            let canvas_y = self.curve_to_canvas(Vec2::new(0.0, to_view_y(d))).y;
+            painter.text(
+                to_screen.transform_pos(Pos2::new(self.editor_size.x - 5.0, canvas_y)),
+                egui::Align2::RIGHT_CENTER,
+                format!("{:.prec$}", d, prec = precision),
+                egui::FontId::default(),
+                COLOR,
+            );
+        }
+        painter.text(
+            to_screen.transform_pos(Pos2::new(self.editor_size.x - 5.0, 5.0)),
+            egui::Align2::RIGHT_TOP,
+            "dy/dx",
+            egui::FontId::default(),
+            COLOR,
+        );
+    }
+
+    /// Fills the area between the curve and y = 0, as one convex polygon per line segment of the curve
+    fn paint_fill(&self, painter: &Painter, to_screen: &emath::RectTransform, curve: &LookupCurve) {
+        const BEZIER_STEPS: usize = 24;
+
+        // Flatten the curve into a polyline
+        let mut points = Vec::new();
+        for (i, w) in curve.knots().windows(2).enumerate() {
+            let (a, b) = (w[0].position, w[1].position);
+            match w[0].interpolation {
+                KnotInterpolation::Constant => points.extend([a, Vec2::new(b.x, a.y), b]),
+                KnotInterpolation::Linear => points.extend([a, b]),
+                KnotInterpolation::Cubic
+                | KnotInterpolation::Cardinal
+                | KnotInterpolation::BSpline
+                | KnotInterpolation::HermiteRaw { .. } => {
+                    let [p0, p1, p2, p3] = curve.segment_bezier(i);
+                    points.extend((0..=BEZIER_STEPS).map(|step| {
+                        let t = step as f32 / BEZIER_STEPS as f32;
+                        let u = 1.0 - t;
+                        p0 * u * u * u
+                            + p1 * 3.0 * u * u * t
+                            + p2 * 3.0 * u * t * t
+                            + p3 * t * t * t
+                    }));
+                }
+            }
+        }
+
+        let to_screen = |p: Vec2| to_screen.transform_pos(self.curve_to_canvas(p));
+        let on_axis = |x: f32| to_screen(Vec2::new(x, 0.0));
+        for w in points.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            let polygons = if a.y * b.y < 0.0 {
+                // Split where the line crosses y = 0 to keep both parts convex
+                let crossing = a.x + (b.x - a.x) * a.y / (a.y - b.y);
+                vec![
+                    vec![on_axis(a.x), to_screen(a), on_axis(crossing)],
+                    vec![on_axis(crossing), to_screen(b), on_axis(b.x)],
+                ]
+            } else {
+                vec![vec![on_axis(a.x), to_screen(a), to_screen(b), on_axis(b.x)]]
+            };
+            for polygon in polygons {
+                painter.add(Shape::convex_polygon(
+                    polygon,
+                    self.fill_color,
+                    Stroke::NONE,
+                ));
+            }
+        }
+    }
+
     fn paint_grid(&mut self, painter: &Painter, to_screen: &emath::RectTransform) {
         // vertical lines
         if self.grid_step_x > 0.0 {
+            let precision = grid_label_precision(self.grid_step_x);
             let grid_offset_x = self.offset.x % self.grid_step_x;
             let grid_x_count = (self.scale.x / self.grid_step_x).ceil() as i32 + 1;
             for i in 0..grid_x_count {
@@ -599,7 +1819,7 @@ impl LookupCurveEguiEditor {
                         self.editor_size.y - 5.,
                     )),
                     egui::Align2::CENTER_BOTTOM,
-                    format!("{:.1}", line_from.x),
+                    format!("{:.prec$}", line_from.x, prec = precision),
                     egui::FontId::default(),
                     Color32::WHITE,
                 );
@@ -608,6 +1828,7 @@ impl LookupCurveEguiEditor {
 
         // horizontal lines
         if self.grid_step_y > 0.0 {
+            let precision = grid_label_precision(self.grid_step_y);
             let grid_offset_y = self.offset.y % self.grid_step_y;
             let grid_y_count = (self.scale.y / self.grid_step_y).ceil() as i32 + 1;
             for i in 0..grid_y_count {
@@ -628,12 +1849,11 @@ impl LookupCurveEguiEditor {
                     .into(),
                 });
 
-                let text_canvas_pos = Pos2::new(5., self.curve_to_canvas(line_from).y);
-                if text_canvas_pos.y < self.editor_size.y - 30. {
+                if let Some(text_canvas_pos) = self.y_label_canvas_pos(line_from.y) {
                     painter.text(
                         to_screen.transform_pos(text_canvas_pos),
-                        egui::Align2::LEFT_CENTER,
-                        format!("{:.1}", line_from.y),
+                        egui::Align2::RIGHT_CENTER,
+                        format!("{:.prec$}", line_from.y, prec = precision),
                         egui::FontId::default(),
                         Color32::WHITE,
                     );
@@ -641,20 +1861,355 @@ impl LookupCurveEguiEditor {
             }
         }
     }
+
+    /// Canvas position of the right edge of the y-axis grid label for `y`, or `None` if the label would be outside the canvas or overlap the x-axis labels.
+    ///
+    /// Labels at the top edge are moved down to stay fully visible.
+    fn y_label_canvas_pos(&self, y: f32) -> Option<Pos2> {
+        const COLUMN_X: f32 = 50.0;
+        const HALF_HEIGHT: f32 = 7.0;
+        const X_LABEL_BAND: f32 = 25.0;
+
+        let canvas_y = self.curve_to_canvas(Vec2::new(0.0, y)).y;
+        let bottom = self.editor_size.y - X_LABEL_BAND - HALF_HEIGHT;
+        (canvas_y >= -0.5 && canvas_y <= bottom)
+            .then(|| Pos2::new(COLUMN_X, canvas_y.max(HALF_HEIGHT)))
+    }
+}
+
+#[derive(Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+/// Editor for a [Vec2LookupCurve], showing the two sub-curves side-by-side together with a preview of the resulting path.
+///
+/// Holds the editor state.
+pub struct Vec2LookupCurveEguiEditor {
+    pub x_editor: LookupCurveEguiEditor,
+    pub y_editor: LookupCurveEguiEditor,
+}
+
+impl Vec2LookupCurveEguiEditor {
+    /// Number of samples used to draw the path preview
+    const PREVIEW_SAMPLES: usize = 100;
+
+    /// Constructs a [Vec2LookupCurveEguiEditor] with the viewports adjusted to fit the sub-curves of the supplied [Vec2LookupCurve].
+    pub fn fitted_to_curve(curve: &Vec2LookupCurve) -> Self {
+        Self {
+            x_editor: LookupCurveEguiEditor::fitted_to_curve(&curve.x_curve),
+            y_editor: LookupCurveEguiEditor::fitted_to_curve(&curve.y_curve),
+        }
+    }
+
+    /// Display the editor in a window
+    ///
+    /// If a `sample` is supplied, it will be displayed as a red dot on the sub-curves and the path.
+    ///
+    /// Returns `true` if the curve was changed during this update
+    pub fn ui_window(
+        &mut self,
+        ctx: &mut egui::Context,
+        id: impl std::hash::Hash,
+        title: &str,
+        curve: &mut Vec2LookupCurve,
+        sample: Option<f32>,
+    ) -> bool {
+        let mut changed = false;
+        egui::Window::new(title).id(Id::new(id)).show(ctx, |ui| {
+            changed = self.ui(ui, curve, sample);
+        });
+        changed
+    }
+
+    /// Display the editor
+    ///
+    /// If a `sample` is supplied, it will be displayed as a red dot on the sub-curves and the path.
+    ///
+    /// Returns `true` if the curve was changed during this update
+    pub fn ui(&mut self, ui: &mut Ui, curve: &mut Vec2LookupCurve, sample: Option<f32>) -> bool {
+        let mut changed = false;
+        ui.columns(3, |columns| {
+            columns[0].push_id("x_curve", |ui| {
+                ui.label("x");
+                changed |= self.x_editor.ui(ui, &mut curve.x_curve, sample);
+            });
+            columns[1].push_id("y_curve", |ui| {
+                ui.label("y");
+                changed |= self.y_editor.ui(ui, &mut curve.y_curve, sample);
+            });
+            columns[2].label("Path");
+            Self::paint_path_preview(&mut columns[2], curve, sample);
+        });
+        changed
+    }
+
+    fn paint_path_preview(ui: &mut Ui, curve: &Vec2LookupCurve, sample: Option<f32>) {
+        Frame::canvas(ui.style()).show(ui, |ui| {
+            let (response, painter) = ui.allocate_painter(
+                emath::Vec2::new(ui.available_width(), ui.available_height()),
+                Sense::hover(),
+            );
+
+            let (t_min, t_max) = curve.domain();
+            let points: Vec<Vec2> = (0..=Self::PREVIEW_SAMPLES)
+                .map(|i| {
+                    let t = t_min + (t_max - t_min) * i as f32 / Self::PREVIEW_SAMPLES as f32;
+                    curve.sample(t)
+                })
+                .collect();
+
+            let (min, max) = points
+                .iter()
+                .fold((Vec2::INFINITY, Vec2::NEG_INFINITY), |(min, max), p| {
+                    (min.min(*p), max.max(*p))
+                });
+            let size = (max - min).max(Vec2::splat(f32::EPSILON));
+
+            // Keep the aspect ratio of the path, with some padding
+            let rect = response.rect.shrink(10.0);
+            let scale = (rect.width() / size.x).min(rect.height() / size.y);
+            let to_screen = |p: Vec2| {
+                let p = (p - (min + max) / 2.0) * scale;
+                rect.center() + emath::Vec2::new(p.x, -p.y)
+            };
+
+            painter.add(Shape::line(
+                points.into_iter().map(to_screen).collect(),
+                Stroke::new(2.0, Color32::GREEN),
+            ));
+
+            if let Some(sample) = sample {
+                painter.add(Shape::circle_filled(
+                    to_screen(curve.sample(sample)),
+                    3.0,
+                    Color32::RED,
+                ));
+            }
+        });
+    }
+}
+
+/// Largest tangent angle that can be entered, as a vertical tangent can not be evaluated
+const MAX_TANGENT_DEGREES: f32 = 89.9;
+
+/// Angle of a tangent slope in degrees, infinite slopes map to ±90°
+fn slope_to_degrees(slope: f32) -> f32 {
+    slope.atan().to_degrees()
+}
+
+/// Slope of a tangent angle in degrees, clamped to [MAX_TANGENT_DEGREES] to keep the slope finite
+fn degrees_to_slope(degrees: f32) -> f32 {
+    degrees
+        .clamp(-MAX_TANGENT_DEGREES, MAX_TANGENT_DEGREES)
+        .to_radians()
+        .tan()
+}
+
+/// Number of decimals needed to tell grid labels apart, based on the magnitude of the grid step.
+///
+/// Steps that are not a whole multiple of their magnitude, like 0.25, get an extra digit.
+fn grid_label_precision(grid_step: f32) -> usize {
+    let precision = (-grid_step.log10().floor()).max(0.0) as usize;
+    let scaled = grid_step * 10f32.powi(precision as i32);
+    if (scaled - scaled.round()).abs() > 1e-3 {
+        precision + 1
+    } else {
+        precision
+    }
 }
 
-fn slope_weight_from_bezier(
-    c0: Vec2,
-    c3: Vec2,
-    endpoint: Vec2,
-    intermediate: Vec2,
-    dir: f32,
-) -> (f32, f32) {
-    if c3.x == c0.x {
-        return (0.0, 1. / 3.);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_restores_flipped_output_clamp() {
+        let mut curve = LookupCurve::with_knots(vec![Knot::at(0.0, 0.2), Knot::at(1.0, 0.8)])
+            .unwrap()
+            .with_output_clamp(0.0, 0.5);
+        let step = UndoStep::new(&curve);
+
+        curve = curve.invert_output();
+        assert_eq!(curve.output_clamp, Some((0.5, 1.0)));
+
+        step.restore(&mut curve);
+        assert_eq!(curve.output_clamp, Some((0.0, 0.5)));
+        assert_eq!(curve.knots()[0].position.y, 0.2);
+        assert_eq!(curve.lookup(1.0), 0.5);
     }
 
-    let dx = c3.x - c0.x;
-    let weight = (intermediate.x - endpoint.x) * dir / dx;
-    ((intermediate.y - endpoint.y) * dir / (dx * weight), weight)
+    #[test]
+    fn switching_back_to_cubic_restores_saved_tangents() {
+        let mut curve = LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.0)
+                .with_interpolation(KnotInterpolation::Cubic)
+                .with_slope(2.0),
+            Knot::at(1.0, 1.0),
+        ])
+        .unwrap();
+        let mut editor = LookupCurveEguiEditor::default();
+
+        let linear = editor.knot_with_interpolation(&curve, 0, KnotInterpolation::Linear);
+        curve.modify_knot(0, linear.with_slope(0.0));
+        assert!(editor.saved_tangents.contains_key(&curve.knots()[0].id));
+
+        let cubic = editor.knot_with_interpolation(&curve, 0, KnotInterpolation::Cubic);
+        assert_eq!(cubic.left_tangent.slope, 2.0);
+        assert_eq!(cubic.right_tangent.slope, 2.0);
+        assert!(editor.saved_tangents.is_empty());
+    }
+
+    #[test]
+    fn playback_loops_over_domain() {
+        let sample_x = |t, loop_mode| {
+            PlaybackState {
+                t,
+                loop_mode,
+                ..Default::default()
+            }
+            .sample_x((1.0, 3.0))
+        };
+
+        assert_eq!(sample_x(0.5, ExtrapolationMode::Clamp), 1.5);
+        assert_eq!(sample_x(2.5, ExtrapolationMode::Clamp), 3.0);
+        assert_eq!(sample_x(2.5, ExtrapolationMode::Repeat), 1.5);
+        assert_eq!(sample_x(2.5, ExtrapolationMode::PingPong), 2.5);
+        assert_eq!(sample_x(4.5, ExtrapolationMode::PingPong), 1.5);
+    }
+
+    #[test]
+    fn tangent_degrees_convert_to_slope() {
+        assert_eq!(slope_to_degrees(0.0), 0.0);
+        assert!((slope_to_degrees(1.0) - 45.0).abs() < 1e-5);
+        assert_eq!(slope_to_degrees(f32::INFINITY), 90.0);
+        assert_eq!(slope_to_degrees(f32::NEG_INFINITY), -90.0);
+
+        assert!((degrees_to_slope(45.0) - 1.0).abs() < 1e-5);
+        assert!((degrees_to_slope(slope_to_degrees(-2.5)) + 2.5).abs() < 1e-4);
+        // Vertical angles are clamped to a steep but finite slope
+        assert!(degrees_to_slope(90.0).is_finite());
+        assert!(degrees_to_slope(90.0) > 500.0);
+        assert!(degrees_to_slope(-90.0) < -500.0);
+    }
+
+    #[test]
+    fn grid_label_precision_follows_step_magnitude() {
+        assert_eq!(grid_label_precision(1000.0), 0);
+        assert_eq!(grid_label_precision(10.0), 0);
+        assert_eq!(grid_label_precision(1.0), 0);
+        assert_eq!(grid_label_precision(2.5), 1);
+        assert_eq!(grid_label_precision(0.5), 1);
+        assert_eq!(grid_label_precision(0.25), 2);
+        assert_eq!(grid_label_precision(0.1), 1);
+        assert_eq!(grid_label_precision(0.05), 2);
+        assert_eq!(grid_label_precision(0.001), 3);
+    }
+
+    #[test]
+    fn y_labels_align_to_column_and_avoid_x_labels() {
+        let mut editor = LookupCurveEguiEditor {
+            editor_size: Vec2::new(200.0, 200.0),
+            ..Default::default()
+        };
+        editor.set_view_rect(0.0, 1.0, 0.0, 1.0);
+
+        // Topmost label is kept inside the canvas
+        assert_eq!(editor.y_label_canvas_pos(1.0), Some(Pos2::new(50.0, 7.0)));
+        assert_eq!(editor.y_label_canvas_pos(0.5), Some(Pos2::new(50.0, 100.0)));
+        assert_eq!(
+            editor.y_label_canvas_pos(0.25),
+            Some(Pos2::new(50.0, 150.0))
+        );
+        // Bottom-most label would overlap the x-axis labels
+        assert_eq!(editor.y_label_canvas_pos(0.0), None);
+        // Outside of the canvas
+        assert_eq!(editor.y_label_canvas_pos(1.5), None);
+    }
+
+    #[test]
+    fn smooth_zoom_reaches_target() {
+        let mut editor = LookupCurveEguiEditor::default();
+        let target = Vec2::new(2.0, 0.5);
+        editor.zoom_target = Some(target);
+
+        let mut steps = 0;
+        while editor.step_smooth_zoom(target) {
+            steps += 1;
+            assert!(steps < 100);
+        }
+        assert!(steps > 1);
+        assert_eq!(editor.scale, target);
+        assert_eq!(editor.zoom_target, None);
+    }
+
+    #[test]
+    fn fit_to_selection_covers_selected_knots() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 2.0),
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(2.0, 1.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+        let mut editor = LookupCurveEguiEditor {
+            editor_size: Vec2::new(200.0, 100.0),
+            selected_knots: vec![curve.knots()[1].id, curve.knots()[2].id],
+            ..Default::default()
+        };
+        editor.fit_to_selection(&curve);
+
+        assert!(editor.offset.abs_diff_eq(Vec2::new(0.8, 0.8), 1e-6));
+        assert!(editor.scale.abs_diff_eq(Vec2::new(1.4, 1.4), 1e-6));
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn state_round_trips_through_sidecar_file() {
+        let dir = std::env::temp_dir().join(format!("lookup_curve_editor_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let ron_path = dir.join("test.curve.ron").to_string_lossy().into_owned();
+        let state_path = LookupCurveEguiEditor::state_path(&ron_path);
+        assert!(state_path.ends_with("test.curve.editor_prefs.ron"));
+
+        let mut editor = LookupCurveEguiEditor::with_save_path(ron_path.clone());
+        editor.set_view_rect(-1.0, 3.0, 0.5, 1.5);
+        editor.grid_step_x = 0.25;
+        editor.tangent_visibility = TangentVisibility::OnSelect;
+        editor.selected_knots.push(1);
+        editor.save_state(&state_path).unwrap();
+
+        let loaded = LookupCurveEguiEditor::with_save_path(ron_path.clone());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded.offset, Vec2::new(-1.0, 0.5));
+        assert_eq!(loaded.scale, Vec2::new(4.0, 1.0));
+        assert_eq!(loaded.grid_step_x, 0.25);
+        assert_eq!(loaded.tangent_visibility, TangentVisibility::OnSelect);
+        assert_eq!(loaded.ron_path, Some(ron_path));
+        assert!(loaded.selected_knots.is_empty());
+    }
+
+    #[test]
+    fn set_view_rect_maps_bounds_to_canvas_corners() {
+        let mut editor = LookupCurveEguiEditor {
+            editor_size: Vec2::new(200.0, 100.0),
+            ..Default::default()
+        };
+        editor.set_view_rect(-1.0, 3.0, 0.5, 1.5);
+
+        assert_eq!(
+            editor.curve_to_canvas(Vec2::new(-1.0, 1.5)),
+            Pos2::new(0.0, 0.0)
+        );
+        assert_eq!(
+            editor.curve_to_canvas(Vec2::new(3.0, 0.5)),
+            Pos2::new(200.0, 100.0)
+        );
+    }
 }
@@ -1,7 +1,14 @@
 mod editor_egui;
 pub use editor_egui::*;
+mod widget;
+pub use widget::LookupCurveWidget;
 
 #[cfg(feature = "editor_bevy")]
 mod editor_bevy;
 #[cfg(feature = "editor_bevy")]
 pub use editor_bevy::*;
+
+#[cfg(feature = "bevy_picking")]
+mod picking;
+#[cfg(feature = "bevy_picking")]
+pub use picking::*;
@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use bevy_app::{App, Plugin, PreUpdate, Update};
+use bevy_ecs::prelude::{Commands, Component, Entity, EventWriter, IntoSystemConfigs, Query, With};
+use bevy_math::Vec2;
+use bevy_picking::{
+    backend::{HitData, PointerHits},
+    pointer::{PointerId, PointerLocation},
+    PickSet, PickingBehavior,
+};
+use bevy_render::camera::Camera;
+use bevy_window::PrimaryWindow;
+
+use super::{editor_bevy::lookup_curve_editor_ui, LookupCurveEditor, LookupCurveEguiEditor};
+
+pub(crate) struct KnotPickingPlugin;
+
+impl Plugin for KnotPickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            sync_knot_handle_hitboxes.after(lookup_curve_editor_ui),
+        )
+        .add_systems(PreUpdate, knot_handle_backend.in_set(PickSet::Backend));
+    }
+}
+
+/// Hitbox for a knot handle in the window of a [LookupCurveEditor], making the handle take part in `bevy_picking`.
+///
+/// One entity is spawned per knot handle and kept in sync with the editor. Observers of e.g. `Pointer<Drag>` on these entities
+/// can use `editor` and `knot_index` to bind dragging a knot to editing a parameter in the scene.
+///
+/// Hit testing assumes that egui points map directly to logical window pixels, which is the default egui scale factor in `bevy_egui`.
+#[derive(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct KnotHandleHitbox {
+    /// The entity holding the [LookupCurveEditor]
+    pub editor: Entity,
+    /// Index of the knot in the edited curve
+    pub knot_index: usize,
+    /// Center of the handle in logical window pixels
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+/// Spawns, moves and despawns [KnotHandleHitbox]es to match the knot handles drawn by each editor
+fn sync_knot_handle_hitboxes(
+    mut commands: Commands,
+    editors: Query<(Entity, &LookupCurveEditor)>,
+    mut hitboxes: Query<(Entity, &mut KnotHandleHitbox)>,
+) {
+    let mut synced = HashSet::new();
+    for (entity, mut hitbox) in &mut hitboxes {
+        let center = editors.get(hitbox.editor).ok().and_then(|(_, editor)| {
+            editor
+                .egui_editor
+                .knot_handle_positions
+                .get(hitbox.knot_index)
+        });
+        match center {
+            Some(center) if synced.insert((hitbox.editor, hitbox.knot_index)) => {
+                if hitbox.center != *center {
+                    hitbox.center = *center;
+                }
+            }
+            _ => commands.entity(entity).despawn(),
+        }
+    }
+
+    for (editor_entity, editor) in &editors {
+        for (knot_index, center) in editor.egui_editor.knot_handle_positions.iter().enumerate() {
+            if !synced.contains(&(editor_entity, knot_index)) {
+                commands.spawn((
+                    KnotHandleHitbox {
+                        editor: editor_entity,
+                        knot_index,
+                        center: *center,
+                        radius: LookupCurveEguiEditor::KNOT_HANDLE_RADIUS,
+                    },
+                    PickingBehavior::default(),
+                ));
+            }
+        }
+    }
+}
+
+/// Picking backend reporting hits on [KnotHandleHitbox]es under each pointer
+fn knot_handle_backend(
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    cameras: Query<(Entity, &Camera)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    hitboxes: Query<(Entity, &KnotHandleHitbox)>,
+    mut output: EventWriter<PointerHits>,
+) {
+    for (pointer, location) in &pointers {
+        let Some(location) = &location.location else {
+            continue;
+        };
+        // egui is drawn on top of the topmost camera rendering to the window of the pointer
+        let Some((camera_entity, camera)) = cameras
+            .iter()
+            .filter(|(_, camera)| {
+                camera.is_active && location.is_in_viewport(camera, &primary_window)
+            })
+            .max_by_key(|(_, camera)| camera.order)
+        else {
+            continue;
+        };
+
+        let picks: Vec<_> = hitboxes
+            .iter()
+            .filter(|(_, hitbox)| hitbox.center.distance(location.position) <= hitbox.radius)
+            .map(|(entity, _)| (entity, HitData::new(camera_entity, 0.0, None, None)))
+            .collect();
+        if !picks.is_empty() {
+            output.send(PointerHits::new(*pointer, picks, camera.order as f32 + 0.9));
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use egui::{emath, Color32, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Widget};
+
+use crate::{LookupCache, LookupCurve};
+
+/// Read-only preview of a [LookupCurve], drawn directly with the [Painter](egui::Painter)
+///
+/// Fills the available space, and fits the domain and y-range of the curve to it. Unlike the [LookupCurveEguiEditor](super::LookupCurveEguiEditor),
+/// it has no interaction or state, which makes it cheap to use in custom tool UIs:
+///
+/// ```
+/// # use bevy_lookup_curve::{editor::LookupCurveWidget, LookupCurve};
+/// # fn preview(ui: &mut egui::Ui, curve: &LookupCurve) {
+/// ui.add(LookupCurveWidget::new(curve).with_color(egui::Color32::YELLOW));
+/// # }
+/// ```
+pub struct LookupCurveWidget<'a> {
+    curve: &'a LookupCurve,
+    color: Color32,
+    stroke_width: f32,
+    show_knots: bool,
+}
+
+impl<'a> LookupCurveWidget<'a> {
+    /// Margin in pixels around the curve, so that the stroke and knots are not clipped
+    const MARGIN: f32 = 4.0;
+
+    pub fn new(curve: &'a LookupCurve) -> Self {
+        Self {
+            curve,
+            color: Color32::GREEN,
+            stroke_width: 1.5,
+            show_knots: false,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color32) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = stroke_width;
+        self
+    }
+
+    /// Draw a dot at the position of each knot
+    pub fn with_knots_shown(mut self, show_knots: bool) -> Self {
+        self.show_knots = show_knots;
+        self
+    }
+}
+
+impl Widget for LookupCurveWidget<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let (response, painter) = ui.allocate_painter(ui.available_size(), Sense::hover());
+        if self.curve.knots().is_empty() || !ui.is_rect_visible(response.rect) {
+            return response;
+        }
+
+        // One sample per pixel
+        let (min_x, max_x) = self.curve.domain();
+        let sample_count = (response.rect.width() as usize).max(2);
+        let mut cache = LookupCache::new();
+        let samples: Vec<(f32, f32)> = (0..sample_count)
+            .map(|i| {
+                let x = min_x + (max_x - min_x) * i as f32 / (sample_count - 1) as f32;
+                (x, self.curve.lookup_cached(x, &mut cache))
+            })
+            .collect();
+
+        let (min_y, max_y) = samples
+            .iter()
+            .map(|(_, y)| *y)
+            .chain(self.curve.knots().iter().map(|knot| knot.position.y))
+            .fold((f32::MAX, f32::MIN), |(min, max), y| {
+                (min.min(y), max.max(y))
+            });
+
+        // Flat axes are centered in the widget
+        let (min_x, max_x) = if max_x > min_x {
+            (min_x, max_x)
+        } else {
+            (min_x - 0.5, max_x + 0.5)
+        };
+        let (min_y, max_y) = if max_y > min_y {
+            (min_y, max_y)
+        } else {
+            (min_y - 0.5, max_y + 0.5)
+        };
+        let to_screen = emath::RectTransform::from_to(
+            Rect::from_min_max(Pos2::new(min_x, max_y), Pos2::new(max_x, min_y)),
+            response.rect.shrink(Self::MARGIN),
+        );
+
+        painter.add(Shape::line(
+            samples
+                .into_iter()
+                .map(|(x, y)| to_screen.transform_pos(Pos2::new(x, y)))
+                .collect(),
+            Stroke::new(self.stroke_width, self.color),
+        ));
+
+        if self.show_knots {
+            for knot in self.curve.knots() {
+                painter.add(Shape::circle_filled(
+                    to_screen.transform_pos(Pos2::new(knot.position.x, knot.position.y)),
+                    self.stroke_width + 1.5,
+                    self.color,
+                ));
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Knot, KnotInterpolation};
+    use bevy_math::Vec2;
+
+    #[test]
+    fn widget_paints_curve_and_knots() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 1.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let ctx = egui::Context::default();
+        let output = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.add(LookupCurveWidget::new(&curve).with_knots_shown(true));
+            });
+        });
+
+        let circles = output
+            .shapes
+            .iter()
+            .filter(|clipped| matches!(clipped.shape, Shape::Circle(_)))
+            .count();
+        let lines = output
+            .shapes
+            .iter()
+            .filter(|clipped| matches!(clipped.shape, Shape::Path(_)))
+            .count();
+        assert_eq!(circles, 2);
+        assert_eq!(lines, 1);
+    }
+}
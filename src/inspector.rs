@@ -170,20 +170,32 @@ fn lookup_curve_miniature(curve: &LookupCurve, id: egui::Id, ui: &mut egui::Ui)
             let points = if curve.knots().len() < 2 {
                 egui_plot::PlotPoints::from_iter(std::iter::empty())
             } else {
-                const SAMPLE_COUNT: i32 = 50;
+                let sample_count = curve.miniature_resolution.max(1);
                 let min_x = curve.knots().first().unwrap().position.x;
                 let max_x = curve.knots().last().unwrap().position.x;
                 let length = max_x - min_x;
                 let mut cache = LookupCache::new();
                 egui_plot::PlotPoints::from_iter(
-                    (0..SAMPLE_COUNT)
-                        .map(|i| min_x + (i as f32 * length / SAMPLE_COUNT as f32))
+                    (0..sample_count)
+                        .map(|i| min_x + (i as f32 * length / sample_count as f32))
                         .map(|x| [x as f64, curve.lookup_cached(x, &mut cache) as f64]),
                 )
             };
 
             let line = egui_plot::Line::new(points).color(egui::Color32::GREEN);
             plot_ui.line(line);
+
+            for knot in curve.knots() {
+                if let Some(annotation) = &knot.annotation {
+                    plot_ui.text(
+                        egui_plot::Text::new(
+                            egui_plot::PlotPoint::new(knot.position.x, knot.position.y),
+                            annotation.as_str(),
+                        )
+                        .color(egui::Color32::LIGHT_GRAY),
+                    );
+                }
+            }
         });
 
     plot_response.response
@@ -7,6 +7,22 @@ pub trait KnotSearch {
     fn search_knots_linear_rev(&self, x: f32) -> usize;
     fn search_knots(&self, x: f32) -> usize;
     fn search_knots_with_cache(&self, x: f32, cached_index: &mut Option<usize>) -> usize;
+    /// `O(1)` search for knots known to be uniformly spaced `step` apart, starting at `x0`, e.g. after [LookupCurve::resample_uniform](crate::LookupCurve::resample_uniform).
+    ///
+    /// Unlike the other searches, a knot exactly at `x` is returned as the start of the segment rather than the end.
+    fn search_knots_uniform(&self, x: f32, x0: f32, step: f32) -> usize;
+}
+
+/// Index of the segment containing `x` among `len` uniformly spaced points, clamped to the valid segments
+#[inline]
+pub(crate) fn uniform_index(len: usize, x: f32, x0: f32, step: f32) -> usize {
+    let i = ((x - x0) / step).floor();
+    // Also maps NaN (zero step) to the first segment
+    if i >= 0.0 {
+        (i as usize).min(len.saturating_sub(2))
+    } else {
+        0
+    }
 }
 
 impl KnotSearch for [Knot] {
@@ -43,7 +59,7 @@ impl KnotSearch for [Knot] {
             self.search_knots(x)
         } else {
             let cached_index = cached_index.unwrap();
-            let cached_knot = self[cached_index];
+            let cached_knot = &self[cached_index];
             if x <= cached_knot.position.x {
                 self[..cached_index].search_knots_linear_rev(x)
             } else {
@@ -54,6 +70,11 @@ impl KnotSearch for [Knot] {
         *cached_index = Some(i);
         i
     }
+
+    #[inline]
+    fn search_knots_uniform(&self, x: f32, x0: f32, step: f32) -> usize {
+        uniform_index(self.len(), x, x0, step)
+    }
 }
 
 #[cfg(test)]
@@ -118,6 +139,26 @@ mod tests {
         assert_eq!(knots.search_knots_with_cache(0.335, &mut Some(1)), 1);
     }
 
+    #[test]
+    fn uniform_finds_knots() {
+        let knots: Vec<Knot> = (0..5)
+            .map(|i| Knot {
+                position: Vec2::new(i as f32 * 0.25, 0.0),
+                ..Default::default()
+            })
+            .collect();
+        assert_eq!(knots.search_knots_uniform(0.1, 0.0, 0.25), 0);
+        assert_eq!(knots.search_knots_uniform(0.25, 0.0, 0.25), 1);
+        assert_eq!(knots.search_knots_uniform(0.6, 0.0, 0.25), 2);
+        assert_eq!(knots.search_knots_uniform(1.0, 0.0, 0.25), 3);
+        for x in [0.1, 0.3, 0.6, 0.9] {
+            assert_eq!(
+                knots.search_knots_uniform(x, 0.0, 0.25),
+                knots.search_knots_binary(x)
+            );
+        }
+    }
+
     #[test]
     fn cached_index_is_updated() {
         let knots = knots();
@@ -131,4 +172,60 @@ mod tests {
         let knots = knots();
         knots.search_knots_with_cache(0.5, &mut Some(9999));
     }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn cache_survives_serialization() {
+        use crate::{LookupCache, LookupCurve};
+
+        let curve = LookupCurve::with_knots(knots().to_vec()).unwrap();
+        let mut cache = LookupCache::new();
+        curve.lookup_cached(0.5, &mut cache);
+
+        let restored: LookupCache = ron::from_str(&ron::to_string(&cache).unwrap()).unwrap();
+        assert_eq!(restored.last_knot_index, Some(1));
+
+        // A restored cache pointing past the knots of a modified curve falls back to a full search
+        let shorter = LookupCurve::with_knots(knots()[..2].to_vec()).unwrap();
+        let mut restored = restored;
+        assert_eq!(
+            shorter.lookup_cached(0.2, &mut restored),
+            shorter.lookup(0.2)
+        );
+        assert_eq!(restored.last_knot_index, Some(0));
+    }
+
+    #[test]
+    fn cache_hysteresis_keeps_segment() {
+        use crate::{KnotInterpolation, LookupCache, LookupCurve};
+
+        let curve = LookupCurve::with_knots(
+            knots()
+                .into_iter()
+                .map(|knot| Knot {
+                    interpolation: KnotInterpolation::Linear,
+                    ..knot
+                })
+                .collect(),
+        )
+        .unwrap();
+
+        let mut cache = LookupCache::new().with_hysteresis(1e-3);
+        curve.lookup_cached(0.3, &mut cache);
+        assert_eq!(cache.last_knot_index, Some(0));
+
+        // Jitter around the knot at 0.334 stays in the first segment
+        for x in [0.3345, 0.3335, 0.3342] {
+            assert!((curve.lookup_cached(x, &mut cache) - x).abs() < 1e-5);
+            assert_eq!(cache.last_knot_index, Some(0));
+        }
+
+        curve.lookup_cached(0.336, &mut cache);
+        assert_eq!(cache.last_knot_index, Some(1));
+
+        let mut cache = LookupCache::new();
+        curve.lookup_cached(0.3, &mut cache);
+        curve.lookup_cached(0.3345, &mut cache);
+        assert_eq!(cache.last_knot_index, Some(1));
+    }
 }
@@ -1,9 +1,38 @@
 use bevy_math::Vec2;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+#[cfg(feature = "binary")]
+mod binary;
+#[cfg(feature = "binary")]
+pub use binary::DecodeError;
+mod curve_lookup;
+pub use curve_lookup::{CurveLookup, DynCurve};
 pub mod knot_search;
 use knot_search::KnotSearch;
 
+mod lookup_table;
+pub use lookup_table::UniformBakedLookupTable;
+#[cfg(feature = "ron")]
+mod migration;
+mod operations;
+pub use operations::{ContinuityViolation, MergeError, ThresholdDirection};
+mod preset;
+pub use preset::CurvePreset;
+mod procedural;
+#[cfg(feature = "bevy_ecs")]
+mod resource;
+#[cfg(feature = "bevy_ecs")]
+pub use resource::LookupCurveResource;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod rust_code;
+#[cfg(feature = "simd")]
+mod simd;
+pub mod svg;
+pub mod unreal;
+mod vec2_curve;
+pub use vec2_curve::{Vec2LookupCache, Vec2LookupCurve};
+
 #[cfg(feature = "bevy_asset")]
 pub mod asset;
 
@@ -32,6 +61,8 @@ impl bevy_app::Plugin for LookupCurvePlugin {
         app.add_plugins(asset::AssetPlugin);
         #[cfg(feature = "editor_bevy")]
         app.add_plugins(editor::EditorPlugin);
+        #[cfg(feature = "bevy_picking")]
+        app.add_plugins(editor::KnotPickingPlugin);
         #[cfg(feature = "inspector-egui")]
         app.add_plugins(inspector::InspectorPlugin);
     }
@@ -47,6 +78,17 @@ pub enum LookupCurveLoadError {
     /// A [RON](ron) Error
     #[error("Could not parse RON for lookup curve: {0}")]
     RonSpannedError(#[from] ron::error::SpannedError),
+    /// A [JSON](serde_json) Error
+    #[cfg(feature = "json")]
+    #[error("Could not parse JSON for lookup curve: {0}")]
+    JsonError(#[from] serde_json::Error),
+    /// A [binary](LookupCurve::from_binary) decoding Error
+    #[cfg(feature = "binary")]
+    #[error("Could not decode binary lookup curve: {0}")]
+    BinaryError(#[from] DecodeError),
+    /// The file extension does not match any supported format
+    #[error("Unsupported lookup curve file extension: {0}")]
+    UnsupportedExtension(String),
 }
 
 #[cfg(feature = "ron")]
@@ -61,6 +103,66 @@ pub enum LookupCurveSaveError {
     RonError(#[from] ron::error::Error),
 }
 
+/// Serializes `value` to RON, formatted the same way for all files written by the crate
+#[cfg(feature = "ron")]
+pub(crate) fn to_pretty_ron<T: serde::Serialize>(value: &T) -> Result<String, ron::error::Error> {
+    let config = ron::ser::PrettyConfig::new()
+        .new_line("\n".to_string())
+        .indentor("  ".to_string());
+
+    ron::ser::to_string_pretty(value, config)
+}
+
+/// Error returned by [LookupCurve::from_bezier_segments]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum BezierImportError {
+    /// The segment at the given index does not start where the previous segment ended
+    Discontinuous(usize),
+    /// A segment moves backwards along the x-axis, which can not be represented by a [LookupCurve]
+    NonMonotoneX,
+}
+
+impl std::fmt::Display for BezierImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Discontinuous(i) => write!(
+                f,
+                "Bezier segment {} does not start where the previous segment ended",
+                i
+            ),
+            Self::NonMonotoneX => write!(
+                f,
+                "Bezier segments must not move backwards along the x-axis"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BezierImportError {}
+
+/// Error returned by [LookupCurve::with_knots]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum KnotError {
+    /// The knots at the given indices share the same x-position
+    DuplicateX { index_a: usize, index_b: usize },
+}
+
+impl std::fmt::Display for KnotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DuplicateX { index_a, index_b } => write!(
+                f,
+                "Knots {} and {} share the same x-position",
+                index_a, index_b
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KnotError {}
+
 /// How a tangent behaves when a knot or its tangents are moved
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
@@ -118,10 +220,32 @@ pub enum KnotInterpolation {
     Constant,
     Linear,
     Cubic,
+    /// Cubic interpolation with slopes computed from the neighbouring knots at evaluation time, using the [LookupCurve::tension] of the curve.
+    ///
+    /// The tangents of the knot are ignored. See [LookupCurve::cardinal_slope].
+    Cardinal,
+    /// Uniform cubic B-spline, using the knot positions as control points.
+    ///
+    /// The curve is C2 continuous within a run of consecutive B-spline knots, but only passes through the first and last knot of the run.
+    /// The tangents of the knot are ignored.
+    BSpline,
+    /// Cubic Hermite interpolation using raw tangent vectors in curve space, as stored by e.g. FBX.
+    ///
+    /// Unlike [KnotInterpolation::Cubic], the tangents are not normalized by the width of the segment.
+    /// The incoming tangent of the next knot is used if it is also [KnotInterpolation::HermiteRaw], otherwise its left tangent slope.
+    /// Tangents pointing backwards in x might make the segment fold over itself. See [Knot::from_hermite_raw].
+    HermiteRaw {
+        tangent_in: Vec2,
+        tangent_out: Vec2,
+    },
 }
 
-#[derive(Copy, Clone, Debug)]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "KnotData", into = "KnotData")
+)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 /// A knot in a [LookupCurve].
 pub struct Knot {
@@ -136,13 +260,24 @@ pub struct Knot {
     /// Right tangent defining out slope and weight
     pub right_tangent: Tangent,
 
+    /// Optional label displayed next to the knot in the editor
+    pub annotation: Option<String>,
+
+    /// Locked knots can not be moved or edited in the editor.
+    ///
+    /// Changes other than unlocking are discarded by [LookupCurve::modify_knot], [LookupCurve::modify_knot_inplace], [LookupCurve::get_knot_mut]
+    /// and [LookupCurve::begin_bulk_edit], and [LookupCurve::delete_knot] does not remove locked knots.
+    /// Operations on the whole curve, like [LookupCurve::shift_x] or [LookupCurve::transform], apply to locked knots as well.
+    pub locked: bool,
+
+    /// Anchored knots can not be moved in the editor, but can still be edited otherwise, and moved programmatically through e.g. [LookupCurve::modify_knot].
+    ///
+    /// Useful for knots the curve must pass through exactly, like `(0, 0)` and `(1, 1)`.
+    pub anchor: bool,
+
     /// Identifier used by editor operations because index might change during modification
     ///
     /// There should not be any need to change this as it will be set internally.
-    #[cfg_attr(
-        feature = "serialize",
-        serde(skip_serializing, default = "unique_knot_id")
-    )]
     #[cfg_attr(
         feature = "bevy_reflect",
         reflect(skip_serializing, default = "unique_knot_id")
@@ -150,11 +285,65 @@ pub struct Knot {
     pub id: usize,
 }
 
+static KNOT_ID_COUNTER: AtomicUsize = AtomicUsize::new(1);
+
 fn unique_knot_id() -> usize {
-    static KNOT_ID_COUNTER: AtomicUsize = AtomicUsize::new(1);
     KNOT_ID_COUNTER.fetch_add(1, Ordering::Relaxed)
 }
 
+/// Serialized representation of a [Knot]. Keeps the knot id across save/load cycles.
+#[cfg(feature = "serialize")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct KnotData {
+    position: Vec2,
+    interpolation: KnotInterpolation,
+    left_tangent: Tangent,
+    right_tangent: Tangent,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    annotation: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    locked: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    anchor: bool,
+    /// Missing in files saved by older versions, in which case a new id is generated
+    #[serde(default = "unique_knot_id")]
+    id: usize,
+}
+
+#[cfg(feature = "serialize")]
+impl From<KnotData> for Knot {
+    fn from(data: KnotData) -> Self {
+        // Make sure knots created after loading do not reuse the restored id
+        KNOT_ID_COUNTER.fetch_max(data.id.saturating_add(1), Ordering::Relaxed);
+        Self {
+            position: data.position,
+            interpolation: data.interpolation,
+            left_tangent: data.left_tangent,
+            right_tangent: data.right_tangent,
+            annotation: data.annotation,
+            locked: data.locked,
+            anchor: data.anchor,
+            id: data.id,
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl From<Knot> for KnotData {
+    fn from(knot: Knot) -> Self {
+        Self {
+            position: knot.position,
+            interpolation: knot.interpolation,
+            left_tangent: knot.left_tangent,
+            right_tangent: knot.right_tangent,
+            annotation: knot.annotation,
+            locked: knot.locked,
+            anchor: knot.anchor,
+            id: knot.id,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Hash)]
 pub enum TangentSide {
     Left,
@@ -162,9 +351,96 @@ pub enum TangentSide {
 }
 
 impl Knot {
-    /// Returns a new knot copied from self, with the tangent slope decided by `side` set to `slope`. This might also affect the other tangent depending on [`TangentMode`].
+    /// Constructs a knot at `(x, y)`, with all other fields at their default values.
+    ///
+    /// Combine with the other builder methods for a fluent construction of knots:
+    ///
+    /// ```
+    /// # use bevy_lookup_curve::{Knot, KnotInterpolation};
+    /// let knot = Knot::at(0.5, 1.0)
+    ///     .with_interpolation(KnotInterpolation::Cubic)
+    ///     .with_slopes(0.0, 2.0);
+    /// ```
+    pub fn at(x: f32, y: f32) -> Self {
+        Self {
+            position: Vec2::new(x, y),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the knot with the interpolation set to `interpolation`
+    pub fn with_interpolation(mut self, interpolation: KnotInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Returns the knot with both tangent slopes set to `slope`
+    pub fn with_slope(self, slope: f32) -> Self {
+        self.with_slopes(slope, slope)
+    }
+
+    /// Returns the knot with the left and right tangent slopes set to `left` and `right`
+    pub fn with_slopes(mut self, left: f32, right: f32) -> Self {
+        self.left_tangent.slope = left;
+        self.right_tangent.slope = right;
+        self
+    }
+
+    /// Returns the knot with the left and right tangent weights set to `left` and `right`. Weights will be clamped between 0 and 1.
+    pub fn with_weights(mut self, left: Option<f32>, right: Option<f32>) -> Self {
+        self.left_tangent.weight = left.map(|w| w.clamp(0.0, 1.0));
+        self.right_tangent.weight = right.map(|w| w.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Constructs a [KnotInterpolation::HermiteRaw] knot from its position and raw tangent vectors.
+    ///
+    /// The slopes of the knot tangents are set to match, so neighbouring [KnotInterpolation::Cubic] segments line up.
+    pub fn from_hermite_raw(pos: Vec2, tin: Vec2, tout: Vec2) -> Self {
+        let slope = |tangent: Vec2| {
+            let slope = tangent.y / tangent.x;
+            if slope.is_finite() {
+                slope
+            } else {
+                0.0
+            }
+        };
+        Self {
+            position: pos,
+            interpolation: KnotInterpolation::HermiteRaw {
+                tangent_in: tin,
+                tangent_out: tout,
+            },
+            left_tangent: Tangent {
+                slope: slope(tin),
+                mode: TangentMode::Free,
+                weight: None,
+            },
+            right_tangent: Tangent {
+                slope: slope(tout),
+                mode: TangentMode::Free,
+                weight: None,
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Returns a new knot cloned from self, converted to [KnotInterpolation::HermiteRaw] with tangent vectors computed from the tangent slopes.
+    ///
+    /// The slopes are scaled by the width of the segment on each side (`in_width` and `out_width`), which keeps the shape of unweighted cubic segments.
+    pub fn to_hermite_raw(&self, in_width: f32, out_width: f32) -> Self {
+        Self {
+            interpolation: KnotInterpolation::HermiteRaw {
+                tangent_in: Vec2::new(in_width, in_width * self.left_tangent.slope),
+                tangent_out: Vec2::new(out_width, out_width * self.right_tangent.slope),
+            },
+            ..self.clone()
+        }
+    }
+
+    /// Returns a new knot cloned from self, with the tangent slope decided by `side` set to `slope`. This might also affect the other tangent depending on [`TangentMode`].
     pub fn with_tangent_slope(&self, side: TangentSide, slope: f32) -> Self {
-        let mut knot = *self;
+        let mut knot = self.clone();
 
         let aligned = matches!(
             (self.left_tangent.mode, self.right_tangent.mode),
@@ -181,9 +457,9 @@ impl Knot {
         knot
     }
 
-    /// Returns a new knot copied from self, with the tangent mode decided by `side` set to `mode`.
+    /// Returns a new knot cloned from self, with the tangent mode decided by `side` set to `mode`.
     pub fn with_tangent_mode(&self, side: TangentSide, mode: TangentMode) -> Self {
-        let mut knot = *self;
+        let mut knot = self.clone();
         match side {
             TangentSide::Left => knot.left_tangent.mode = mode,
             TangentSide::Right => knot.right_tangent.mode = mode,
@@ -191,9 +467,9 @@ impl Knot {
         knot
     }
 
-    /// Returns a new knot copied from self, with the tangent weight decided by `side` set to `weight`. Weights will be clamped between 0 and 1.
+    /// Returns a new knot cloned from self, with the tangent weight decided by `side` set to `weight`. Weights will be clamped between 0 and 1.
     pub fn with_tangent_weight(&self, side: TangentSide, weight: Option<f32>) -> Self {
-        let mut knot = *self;
+        let mut knot = self.clone();
         let weight = weight.map(|w| w.clamp(0.0, 1.0));
         match side {
             TangentSide::Left => knot.left_tangent.weight = weight,
@@ -224,6 +500,41 @@ impl Knot {
     }
 }
 
+/// Inverse of [Knot::compute_bezier_to] for one side of a segment. Returns the tangent slope and weight given the segment endpoints `c0` and `c3`, the `endpoint` the tangent belongs to, and its `intermediate` control point.
+///
+/// `dir` is `1.0` for a right tangent and `-1.0` for a left tangent.
+pub(crate) fn slope_weight_from_bezier(
+    c0: Vec2,
+    c3: Vec2,
+    endpoint: Vec2,
+    intermediate: Vec2,
+    dir: f32,
+) -> (f32, f32) {
+    if c3.x == c0.x {
+        return (0.0, 1. / 3.);
+    }
+
+    let dx = c3.x - c0.x;
+    let weight = (intermediate.x - endpoint.x) * dir / dx;
+    ((intermediate.y - endpoint.y) * dir / (dx * weight), weight)
+}
+
+/// Computes the right tangent of the first knot and the left tangent of the second knot from the four control points of a bezier segment.
+///
+/// The tangents are weighted and use [TangentMode::Free], in order to reproduce the segment exactly.
+pub(crate) fn tangents_from_bezier(points: [Vec2; 4]) -> (Tangent, Tangent) {
+    let [c0, c1, c2, c3] = points;
+    let tangent = |endpoint, intermediate, dir| {
+        let (slope, weight) = slope_weight_from_bezier(c0, c3, endpoint, intermediate, dir);
+        Tangent {
+            slope: if slope.is_finite() { slope } else { 0.0 },
+            mode: TangentMode::Free,
+            weight: Some(weight.clamp(0.0, 1.0)),
+        }
+    };
+    (tangent(c0, c1, 1.0), tangent(c3, c2, -1.0))
+}
+
 impl Default for Knot {
     fn default() -> Self {
         Self {
@@ -232,22 +543,215 @@ impl Default for Knot {
             id: unique_knot_id(),
             right_tangent: Tangent::default_right(),
             left_tangent: Tangent::default_left(),
+            annotation: None,
+            locked: false,
+            anchor: false,
+        }
+    }
+}
+
+/// Mutable access to a knot in a [LookupCurve], see [LookupCurve::get_knot_mut]
+///
+/// If the x-position of the knot was changed, the knots are re-sorted when the guard is dropped.
+/// If the knot is [locked](Knot::locked), all changes other than unlocking are reverted when the guard is dropped. A NaN position is reverted as well.
+pub struct KnotMutGuard<'a> {
+    curve: &'a mut LookupCurve,
+    index: usize,
+    original_position: Vec2,
+    locked_original: Option<Knot>,
+}
+
+impl std::ops::Deref for KnotMutGuard<'_> {
+    type Target = Knot;
+
+    fn deref(&self) -> &Knot {
+        &self.curve.knots[self.index]
+    }
+}
+
+impl std::ops::DerefMut for KnotMutGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Knot {
+        &mut self.curve.knots[self.index]
+    }
+}
+
+impl Drop for KnotMutGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(original) = self.locked_original.take() {
+            let knot = &mut self.curve.knots[self.index];
+            *knot = Knot {
+                locked: knot.locked,
+                ..original
+            };
+        } else if self.curve.knots[self.index].position.is_nan() {
+            self.curve.knots[self.index].position = self.original_position;
+        } else if self.curve.knots[self.index].position.x != self.original_position.x {
+            self.curve.resort_knot(self.index);
         }
     }
 }
 
+/// Mutable access to all knots in a [LookupCurve], see [LookupCurve::begin_bulk_edit]
+///
+/// The knots are sorted once when the guard is dropped. Changes to [locked](Knot::locked) knots other than unlocking are reverted, and deleted locked knots are restored.
+pub struct BulkEdit<'a> {
+    curve: &'a mut LookupCurve,
+    locked_knots: Vec<Knot>,
+}
+
+impl BulkEdit<'_> {
+    /// Returns the knots of the curve for mutation. Knots may be added, removed and moved freely, as they are sorted when the guard is dropped.
+    pub fn knots_mut(&mut self) -> &mut Vec<Knot> {
+        &mut self.curve.knots
+    }
+}
+
+impl Drop for BulkEdit<'_> {
+    fn drop(&mut self) {
+        let knots = &mut self.curve.knots;
+        for original in self.locked_knots.drain(..) {
+            match knots.iter_mut().find(|knot| knot.id == original.id) {
+                Some(knot) => {
+                    *knot = Knot {
+                        locked: knot.locked,
+                        ..original
+                    }
+                }
+                None => knots.push(original),
+            }
+        }
+        sort_and_validate_knots(&mut self.curve.knots);
+        debug_assert!(
+            std::thread::panicking()
+                || self
+                    .curve
+                    .knots
+                    .windows(2)
+                    .all(|w| { w[1].position.x - w[0].position.x >= LookupCurve::KNOT_X_EPSILON }),
+            "bulk edit left knots sharing the same x-position"
+        );
+    }
+}
+
+/// Sorts knots by x-position. Unlike `partial_cmp`, this does not panic on NaN, but places knots with a NaN x-position last.
+fn sort_knots(knots: &mut [Knot]) {
+    knots.sort_by(|a, b| {
+        let (a, b) = (a.position.x, b.position.x);
+        a.is_nan().cmp(&b.is_nan()).then(a.total_cmp(&b))
+    });
+}
+
+/// Knots with a NaN x or y position can not be evaluated, and are removed from curves
+fn is_valid_knot(knot: &Knot) -> bool {
+    !knot.position.is_nan()
+}
+
+/// Sorts knots by x-position and removes invalid knots, see [is_valid_knot]
+fn sort_and_validate_knots(knots: &mut Vec<Knot>) {
+    sort_knots(knots);
+
+    let len = knots.len();
+    knots.retain(is_valid_knot);
+    if knots.len() < len {
+        #[cfg(feature = "bevy_app")]
+        bevy_log::warn!(
+            "Removed {} knot(s) with NaN position from lookup curve",
+            len - knots.len()
+        );
+    }
+}
+
+/// A segment between two neighbouring knots in a [LookupCurve], see [LookupCurve::segments]
+#[derive(Copy, Clone, Debug)]
+pub struct CurveSegment<'a> {
+    pub left_knot: &'a Knot,
+    pub right_knot: &'a Knot,
+    /// Interpolation used within the segment, decided by the left knot
+    pub interpolation: KnotInterpolation,
+    curve: &'a LookupCurve,
+    index: usize,
+}
+
+impl CurveSegment<'_> {
+    /// Find y for given x within the segment. `x` is clamped to the segment range.
+    ///
+    /// The [LookupCurve::output_clamp] of the curve is not applied.
+    pub fn eval(&self, x: f32) -> f32 {
+        let x = x.clamp(self.left_knot.position.x, self.right_knot.position.x);
+        if x == self.right_knot.position.x {
+            return self.right_knot.position.y;
+        }
+        self.curve.interpolate(self.index, x)
+    }
+}
+
 /// Cache to speed up coherent lookups, see [LookupCurve::lookup_cached]
+///
+/// Can be serialized to checkpoint the lookup state. A cache that no longer matches the knots of the curve is detected and rebuilt on the next lookup.
 #[derive(Copy, Clone, Debug, Default)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct LookupCache {
     last_knot_index: Option<usize>,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    hysteresis: f32,
 }
 
 impl LookupCache {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Keeps using the cached segment until `x` is more than `epsilon` outside of it, defaults to `0.0`.
+    ///
+    /// Stabilizes the cache for x-values jittering around a knot, at the cost of slightly extrapolating the cached segment within the band.
+    pub fn with_hysteresis(mut self, epsilon: f32) -> Self {
+        self.hysteresis = epsilon;
+        self
+    }
+}
+
+/// Strategy used to solve for the curve parameter `t` given `x` in weighted cubic segments
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum WeightedCubicSolver {
+    /// Newton-Raphson iteration starting from the normalized x-position within the segment.
+    ///
+    /// Gives the same results as the initial guess `t = x` used before the solver was configurable, which only converged for segments within `0..=1`.
+    ///
+    /// Converges in a few iterations for most curves, but may need many iterations (or fail to converge within `max_iters`) when the tangent weights are very asymmetric.
+    #[default]
+    NewtonRaphsonFromX,
+    /// Newton-Raphson iteration starting from the middle of the segment.
+    ///
+    /// Can be a better starting point than [WeightedCubicSolver::NewtonRaphsonFromX] for heavily skewed segments, but is slower for near-linear segments.
+    NewtonRaphsonFromMidpoint,
+    /// Binary search over the segment.
+    ///
+    /// Always converges since weighted cubic segments are monotonic in x, but needs more iterations than Newton-Raphson to reach the same precision.
+    Bisection,
+}
+
+/// Event tagged at an x-position of a [LookupCurve], e.g. to trigger sounds or effects at a specific time of an animation.
+///
+/// See [LookupCurve::events_between].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct KnotEvent {
+    pub x: f32,
+    /// Tag used to identify the event, e.g. to decide which game event to send
+    pub event_tag: String,
+}
+
+impl KnotEvent {
+    pub fn new<S: Into<String>>(x: f32, event_tag: S) -> Self {
+        Self {
+            x,
+            event_tag: event_tag.into(),
+        }
+    }
 }
 
 const fn max_iters_default() -> u8 {
@@ -256,12 +760,25 @@ const fn max_iters_default() -> u8 {
 const fn max_error_default() -> f32 {
     1e-5
 }
+const fn tension_default() -> f32 {
+    0.5
+}
+const fn miniature_resolution_default() -> u32 {
+    50
+}
 
 /// Two-dimensional spline that only allows a single y-value per x-value
+///
+/// Can be stored as an asset, a resource or a component. All mutating methods take `&mut self`,
+/// so edits through `Mut<LookupCurve>` are picked up by Bevy's change detection (`Changed<LookupCurve>`).
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "bevy_asset", derive(bevy_asset::Asset))]
+#[cfg_attr(
+    feature = "bevy_ecs",
+    derive(bevy_ecs::system::Resource, bevy_ecs::component::Component)
+)]
 pub struct LookupCurve {
     knots: Vec<Knot>,
 
@@ -285,8 +802,40 @@ pub struct LookupCurve {
         reflect(skip_serializing, default = "max_error_default")
     )]
     pub max_error: f32,
+    /// Strategy used to solve weighted cubic segments
+    #[cfg_attr(feature = "serialize", serde(skip_serializing, default))]
+    #[cfg_attr(feature = "bevy_reflect", reflect(skip_serializing, default))]
+    pub cubic_solver: WeightedCubicSolver,
+
+    /// If set, lookups will be clamped between min and max
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub output_clamp: Option<(f32, f32)>,
+
+    /// Tension used for [KnotInterpolation::Cardinal] segments and auto-computed tangents, see [LookupCurve::cardinal_slope]
+    #[cfg_attr(feature = "serialize", serde(default = "tension_default"))]
+    #[cfg_attr(feature = "bevy_reflect", reflect(default = "tension_default"))]
+    pub tension: f32,
 
     pub name: Option<String>,
+
+    /// Events tagged at x-positions of the curve, see [LookupCurve::events_between]
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub events: Vec<KnotEvent>,
+
+    /// Number of samples used to draw the miniature of the curve in the inspector
+    ///
+    /// Increase for curves with many closely spaced knots, which might otherwise be misrepresented.
+    #[cfg_attr(feature = "serialize", serde(default = "miniature_resolution_default"))]
+    #[cfg_attr(
+        feature = "bevy_reflect",
+        reflect(default = "miniature_resolution_default")
+    )]
+    pub miniature_resolution: u32,
+
+    /// Version of the serialization format, defaulting to `0` for files written before the format was versioned
+    #[cfg_attr(feature = "serialize", serde(default))]
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    format_version: u32,
 }
 
 impl Default for LookupCurve {
@@ -295,19 +844,61 @@ impl Default for LookupCurve {
             knots: vec![],
             max_iters: max_iters_default(),
             max_error: max_error_default(),
+            cubic_solver: WeightedCubicSolver::default(),
+            output_clamp: None,
+            tension: tension_default(),
             name: None,
+            events: vec![],
+            miniature_resolution: miniature_resolution_default(),
+            format_version: Self::FORMAT_VERSION,
         }
     }
 }
 
 impl LookupCurve {
-    pub fn new(mut knots: Vec<Knot>) -> Self {
-        knots.sort_by(|a, b| {
-            a.position
-                .x
-                .partial_cmp(&b.position.x)
-                .expect("NaN is not allowed")
-        });
+    /// Smallest allowed distance between the x-positions of two knots, see [LookupCurve::with_knots]
+    pub const KNOT_X_EPSILON: f32 = 1e-6;
+
+    /// Version of the serialization format written by this version of the crate.
+    ///
+    /// Files written before the format was versioned are treated as version `0`, see [LookupCurve::migrate].
+    pub const FORMAT_VERSION: u32 = 1;
+
+    #[deprecated(
+        since = "0.7.0",
+        note = "does not validate the knots, use `LookupCurve::with_knots` instead"
+    )]
+    pub fn new(knots: Vec<Knot>) -> Self {
+        Self::new_unchecked(knots)
+    }
+
+    /// Constructs a [LookupCurve] from the supplied knots, sorted by x-position.
+    ///
+    /// Returns [KnotError::DuplicateX] if two knots share the same x-position (within [LookupCurve::KNOT_X_EPSILON]),
+    /// with the indices referring to the supplied `knots`. Knots with a NaN position are removed rather than causing a panic.
+    pub fn with_knots(knots: Vec<Knot>) -> Result<LookupCurve, KnotError> {
+        // Invalid knots are removed by `new_unchecked`, and can not be duplicates
+        let mut order: Vec<usize> = (0..knots.len())
+            .filter(|i| is_valid_knot(&knots[*i]))
+            .collect();
+        order.sort_by(|a, b| knots[*a].position.x.total_cmp(&knots[*b].position.x));
+        for w in order.windows(2) {
+            if knots[w[1]].position.x - knots[w[0]].position.x < Self::KNOT_X_EPSILON {
+                return Err(KnotError::DuplicateX {
+                    index_a: w[0].min(w[1]),
+                    index_b: w[0].max(w[1]),
+                });
+            }
+        }
+
+        Ok(Self::new_unchecked(knots))
+    }
+
+    /// Constructs a [LookupCurve] from the supplied knots, sorted by x-position, without checking for duplicates.
+    ///
+    /// Knots with a NaN position are removed.
+    pub(crate) fn new_unchecked(mut knots: Vec<Knot>) -> Self {
+        sort_and_validate_knots(&mut knots);
 
         Self {
             knots,
@@ -315,6 +906,89 @@ impl LookupCurve {
         }
     }
 
+    /// Constructs a [LookupCurve] from a chain of bezier segments, each given as four control points.
+    ///
+    /// Each segment must start where the previous segment ended, and all control points must be within the x-range of their segment.
+    /// The knots use [KnotInterpolation::Cubic] with weighted tangents, reproducing the segments exactly.
+    pub fn from_bezier_segments(segments: &[[Vec2; 4]]) -> Result<LookupCurve, BezierImportError> {
+        let mut knots: Vec<Knot> = Vec::with_capacity(segments.len() + 1);
+
+        for (i, segment) in segments.iter().enumerate() {
+            let [c0, c1, c2, c3] = *segment;
+            if [c0.x, c1.x, c2.x].iter().any(|x| *x > c3.x) || c1.x < c0.x || c2.x < c0.x {
+                return Err(BezierImportError::NonMonotoneX);
+            }
+
+            let (right_tangent, left_tangent) = tangents_from_bezier(*segment);
+            match knots.last_mut() {
+                Some(knot) if !knot.position.abs_diff_eq(c0, 1e-5) => {
+                    return Err(BezierImportError::Discontinuous(i));
+                }
+                Some(knot) => {
+                    knot.interpolation = KnotInterpolation::Cubic;
+                    knot.right_tangent = right_tangent;
+                }
+                None => knots.push(Knot {
+                    position: c0,
+                    interpolation: KnotInterpolation::Cubic,
+                    right_tangent,
+                    ..Default::default()
+                }),
+            }
+            knots.push(Knot {
+                position: c3,
+                interpolation: KnotInterpolation::Cubic,
+                left_tangent,
+                ..Default::default()
+            });
+        }
+
+        Ok(LookupCurve::new_unchecked(knots))
+    }
+
+    /// Constructs a [LookupCurve] from a step function given as `(x_start, y_value)` steps, for discrete lookups like level brackets or damage thresholds.
+    ///
+    /// Each step becomes a [KnotInterpolation::Constant] knot holding `y_value` until the start of the next step.
+    /// The steps do not need to be sorted. The last step holds its value for all x beyond it.
+    ///
+    /// # Panics
+    /// Panics if two steps share the same `x_start` (within [LookupCurve::KNOT_X_EPSILON]).
+    pub fn from_step_function(steps: &[(f32, f32)]) -> LookupCurve {
+        LookupCurve::with_knots(
+            steps
+                .iter()
+                .map(|&(x, y)| Knot::at(x, y).with_interpolation(KnotInterpolation::Constant))
+                .collect(),
+        )
+        .expect("from_step_function steps must have distinct x_start values")
+    }
+
+    /// Constructs a cubic [LookupCurve] from Hermite spline points given as `(x, y, left_slope, right_slope)`.
+    ///
+    /// The tangents use [TangentMode::Free], so the left and right slopes can differ.
+    pub fn from_hermite_spline(points: &[(f32, f32, f32, f32)]) -> LookupCurve {
+        LookupCurve::new_unchecked(
+            points
+                .iter()
+                .map(|&(x, y, left_slope, right_slope)| Knot {
+                    position: Vec2::new(x, y),
+                    interpolation: KnotInterpolation::Cubic,
+                    left_tangent: Tangent {
+                        slope: left_slope,
+                        mode: TangentMode::Free,
+                        weight: None,
+                    },
+                    right_tangent: Tangent {
+                        slope: right_slope,
+                        mode: TangentMode::Free,
+                        weight: None,
+                    },
+                    ..Default::default()
+                })
+                .collect(),
+        )
+    }
+
     /// Consumes the curve and returns it with max_iters set to the new value
     pub fn with_max_iters(mut self, max_iters: u8) -> Self {
         self.max_iters = max_iters;
@@ -327,6 +1001,89 @@ impl LookupCurve {
         self
     }
 
+    /// Consumes the curve and returns it with cubic_solver set to the new value
+    pub fn with_cubic_solver(mut self, cubic_solver: WeightedCubicSolver) -> Self {
+        self.cubic_solver = cubic_solver;
+        self
+    }
+
+    /// Consumes the curve and returns it with lookups clamped between `min` and `max`
+    pub fn with_output_clamp(mut self, min: f32, max: f32) -> Self {
+        self.output_clamp = Some((min, max));
+        self
+    }
+
+    /// Consumes the curve and returns it with the supplied [LookupCurve::tension]
+    pub fn with_tension(mut self, tension: f32) -> Self {
+        self.tension = tension;
+        self
+    }
+
+    /// Consumes the curve and returns it with the slope set at both ends, e.g. to build a clamped cubic spline
+    /// or to match the endpoint derivatives of a simulation.
+    ///
+    /// Sets the right tangent slope of the first knot to `left_slope`, and the left tangent slope of the last knot to `right_slope`.
+    /// Only affects the curve if the outer segments use [KnotInterpolation::Cubic].
+    pub fn with_endpoint_slopes(mut self, left_slope: f32, right_slope: f32) -> Self {
+        if let Some(first) = self.knots.first_mut() {
+            first.right_tangent.slope = left_slope;
+        }
+        if let Some(last) = self.knots.last_mut() {
+            last.left_tangent.slope = right_slope;
+        }
+        self
+    }
+
+    /// Consumes the curve and returns it with endpoint slopes giving zero curvature (second derivative) at both ends,
+    /// as in a natural cubic spline.
+    ///
+    /// The slopes are computed from the outer segments assuming unweighted tangents, keeping the inner slope of each segment.
+    /// With only two knots, both slopes are set to the slope of the line between them.
+    pub fn with_natural_endpoints(self) -> Self {
+        let n = self.knots.len();
+        if n < 2 {
+            return self;
+        }
+
+        let secant =
+            |a: &Knot, b: &Knot| (b.position.y - a.position.y) / (b.position.x - a.position.x);
+        if n == 2 {
+            let slope = secant(&self.knots[0], &self.knots[1]);
+            return self.with_endpoint_slopes(slope, slope);
+        }
+
+        // For a hermite segment with slopes m0 and m1, the second derivative is zero at the start when m0 = (3d - m1) / 2,
+        // and at the end when m1 = (3d - m0) / 2, where d is the secant slope.
+        let left_slope =
+            (3.0 * secant(&self.knots[0], &self.knots[1]) - self.knots[1].left_tangent.slope) / 2.0;
+        let right_slope = (3.0 * secant(&self.knots[n - 2], &self.knots[n - 1])
+            - self.knots[n - 2].right_tangent.slope)
+            / 2.0;
+        self.with_endpoint_slopes(left_slope, right_slope)
+    }
+
+    /// Consumes the curve and returns it with the supplied [LookupCurve::events]
+    pub fn with_events(mut self, events: Vec<KnotEvent>) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Returns the events with an x-position in the half-open interval `[x_start, x_end)`.
+    ///
+    /// Calling this every frame with the previous and current x gives each event exactly once, e.g.:
+    /// ```
+    /// # use bevy_lookup_curve::{KnotEvent, LookupCurve};
+    /// let curve = LookupCurve::default().with_events(vec![KnotEvent::new(0.5, "footstep")]);
+    /// let fired: Vec<_> = curve.events_between(0.4, 0.6).map(|e| e.event_tag.as_str()).collect();
+    /// assert_eq!(fired, ["footstep"]);
+    /// assert_eq!(curve.events_between(0.6, 0.8).count(), 0);
+    /// ```
+    pub fn events_between(&self, x_start: f32, x_end: f32) -> impl Iterator<Item = &KnotEvent> {
+        self.events
+            .iter()
+            .filter(move |event| event.x >= x_start && event.x < x_end)
+    }
+
     /// Consumes the curve and returns it with name set
     pub fn with_name<S: Into<String>>(mut self, name: S) -> Self {
         self.name = Some(name.into());
@@ -339,31 +1096,264 @@ impl LookupCurve {
     }
 
     #[cfg(feature = "ron")]
-    /// Serializes the lookup curve and saves it as a RON file
+    /// Loads a lookup curve from a RON file, migrating it from older versions of the format if needed
     pub fn load_from_file(path: &str) -> Result<Self, LookupCurveLoadError> {
         let bytes = std::fs::read(path)?;
-        let lookup_curve = ron::de::from_bytes::<LookupCurve>(&bytes)?;
-        Ok(lookup_curve)
+        Self::from_ron_bytes(&bytes)
+    }
+
+    /// Serializes the lookup curve to pretty-printed RON, as written by [LookupCurve::save_to_file]
+    #[cfg(feature = "ron")]
+    pub(crate) fn to_ron_string(&self) -> Result<String, ron::error::Error> {
+        to_pretty_ron(self)
     }
 
     #[cfg(feature = "ron")]
     /// Serializes the lookup curve and saves it as a RON file
+    ///
+    /// The file is pretty-printed with one field per line and `\n` line endings on all platforms, to be readable in text editors and diff tools.
     pub fn save_to_file(&self, path: &str) -> Result<(), LookupCurveSaveError> {
-        let config = ron::ser::PrettyConfig::new()
-            .new_line("\n".to_string())
-            .indentor("  ".to_string());
+        let s = self.to_ron_string()?;
+        std::fs::write(path, s.as_bytes())?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "ron")]
+    /// Saves the lookup curve like [LookupCurve::save_to_file], but keeps up to `max_versions` previous versions of the file as backups.
+    ///
+    /// Before writing, an existing file is renamed to `path.1`, and existing backups are shifted to `path.2`, `path.3`, etc. The oldest backup beyond `max_versions` is removed.
+    pub fn save_to_file_versioned(
+        &self,
+        path: &str,
+        max_versions: usize,
+    ) -> Result<(), LookupCurveSaveError> {
+        let s = self.to_ron_string()?;
+
+        if max_versions > 0 && std::fs::exists(path)? {
+            let backup = |version: usize| format!("{}.{}", path, version);
+            if std::fs::exists(backup(max_versions))? {
+                std::fs::remove_file(backup(max_versions))?;
+            }
+            for version in (1..max_versions).rev() {
+                if std::fs::exists(backup(version))? {
+                    std::fs::rename(backup(version), backup(version + 1))?;
+                }
+            }
+            std::fs::rename(path, backup(1))?;
+        }
 
-        let s = ron::ser::to_string_pretty(self, config)?;
         std::fs::write(path, s.as_bytes())?;
 
         Ok(())
     }
 
+    /// Returns the x-range covered by the knots of the curve as `(min, max)`. Returns `(0.0, 0.0)` for a curve without knots.
+    pub fn domain(&self) -> (f32, f32) {
+        match (self.knots.first(), self.knots.last()) {
+            (Some(first), Some(last)) => (first.position.x, last.position.x),
+            _ => (0.0, 0.0),
+        }
+    }
+
     /// Returns the knots in the curve as a slice
     pub fn knots(&self) -> &[Knot] {
         self.knots.as_slice()
     }
 
+    /// Returns the version of the serialization format of the curve, see [LookupCurve::FORMAT_VERSION]
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    /// Returns an iterator over the four bezier control points of each cubic segment in the curve.
+    ///
+    /// Segments starting at a knot with [KnotInterpolation::Constant] or [KnotInterpolation::Linear] are skipped.
+    pub fn bezier_segments(&self) -> impl Iterator<Item = [Vec2; 4]> + '_ {
+        (0..self.knots.len().saturating_sub(1))
+            .filter(|i| {
+                matches!(
+                    self.knots[*i].interpolation,
+                    KnotInterpolation::Cubic
+                        | KnotInterpolation::Cardinal
+                        | KnotInterpolation::BSpline
+                        | KnotInterpolation::HermiteRaw { .. }
+                )
+            })
+            .map(|i| self.segment_bezier(i))
+    }
+
+    /// Returns the knot positions as a 2D polyline, for using the curve as a path rather than as a function of x
+    pub fn as_polyline(&self) -> Vec<Vec2> {
+        self.knots.iter().map(|knot| knot.position).collect()
+    }
+
+    /// Returns the whole curve as a path of cubic bezier segments, e.g. for drawing it with 2D drawing crates that accept bezier paths.
+    ///
+    /// Unlike [LookupCurve::bezier_segments], no segments are skipped. [KnotInterpolation::Linear] segments are returned as straight bezier segments,
+    /// and [KnotInterpolation::Constant] segments as two straight segments forming a step.
+    pub fn as_bezier_path(&self) -> Vec<[Vec2; 4]> {
+        let line = |a: Vec2, b: Vec2| [a, a.lerp(b, 1.0 / 3.0), a.lerp(b, 2.0 / 3.0), b];
+
+        let mut path = Vec::with_capacity(self.knots.len().saturating_sub(1));
+        for (i, w) in self.knots.windows(2).enumerate() {
+            let (a, b) = (w[0].position, w[1].position);
+            match w[0].interpolation {
+                KnotInterpolation::Constant => {
+                    let step = Vec2::new(b.x, a.y);
+                    path.push(line(a, step));
+                    path.push(line(step, b));
+                }
+                KnotInterpolation::Linear => path.push(line(a, b)),
+                KnotInterpolation::Cubic
+                | KnotInterpolation::Cardinal
+                | KnotInterpolation::BSpline
+                | KnotInterpolation::HermiteRaw { .. } => path.push(self.segment_bezier(i)),
+            }
+        }
+        path
+    }
+
+    /// Returns the bezier control points of the segment starting at knot `i`, resolving the slopes of [KnotInterpolation::Cardinal] segments
+    /// and the control points of [KnotInterpolation::BSpline] segments
+    pub(crate) fn segment_bezier(&self, i: usize) -> [Vec2; 4] {
+        let (knot_a, knot_b) = (&self.knots[i], &self.knots[i + 1]);
+        match knot_a.interpolation {
+            KnotInterpolation::Cardinal => {
+                let third = (knot_b.position.x - knot_a.position.x) / 3.0;
+                [
+                    knot_a.position,
+                    knot_a.position + Vec2::new(third, self.cardinal_slope(i) * third),
+                    knot_b.position - Vec2::new(third, self.cardinal_slope(i + 1) * third),
+                    knot_b.position,
+                ]
+            }
+            KnotInterpolation::HermiteRaw { tangent_out, .. } => {
+                let tangent_in = match knot_b.interpolation {
+                    KnotInterpolation::HermiteRaw { tangent_in, .. } => tangent_in,
+                    _ => {
+                        let width = knot_b.position.x - knot_a.position.x;
+                        Vec2::new(width, width * knot_b.left_tangent.slope)
+                    }
+                };
+                [
+                    knot_a.position,
+                    knot_a.position + tangent_out / 3.0,
+                    knot_b.position - tangent_in / 3.0,
+                    knot_b.position,
+                ]
+            }
+            KnotInterpolation::BSpline => {
+                let (p1, p2) = (knot_a.position, knot_b.position);
+                // Outside of the B-spline run, the control points are mirrored so the curve passes through the end knots
+                let p0 = match self.prev_knot(i) {
+                    Some(k) if matches!(k.interpolation, KnotInterpolation::BSpline) => k.position,
+                    _ => 2.0 * p1 - p2,
+                };
+                let p3 = match self.next_knot(i + 1) {
+                    Some(k) if matches!(knot_b.interpolation, KnotInterpolation::BSpline) => {
+                        k.position
+                    }
+                    _ => 2.0 * p2 - p1,
+                };
+
+                // Bezier form of the uniform cubic B-spline segment, from de Boor knot insertion
+                [
+                    (p0 + 4.0 * p1 + p2) / 6.0,
+                    (2.0 * p1 + p2) / 3.0,
+                    (p1 + 2.0 * p2) / 3.0,
+                    (p1 + 4.0 * p2 + p3) / 6.0,
+                ]
+            }
+            _ => knot_a.compute_bezier_to(knot_b),
+        }
+    }
+
+    /// Returns the bezier segment of the curve containing `x`, given the segment `i` between the knots surrounding `x`
+    fn bezier_segment_at(&self, i: usize, x: f32) -> CubicSegment {
+        let i = match self.knots[i].interpolation {
+            KnotInterpolation::BSpline => self.bspline_segment_containing(i, x),
+            _ => i,
+        };
+        CubicSegment::from_bezier_points(self.segment_bezier(i))
+    }
+
+    /// Returns the index of the [KnotInterpolation::BSpline] segment containing `x`, given the segment `i` between the knots surrounding `x`.
+    ///
+    /// B-spline segments do not start and end at their knots, so `x` might be covered by a neighbouring segment of the same run.
+    fn bspline_segment_containing(&self, i: usize, x: f32) -> usize {
+        let [start, _, _, end] = self.segment_bezier(i);
+        if x < start.x && i > 0 {
+            i - 1
+        } else if x > end.x && i + 2 < self.knots.len() {
+            i + 1
+        } else {
+            i
+        }
+    }
+
+    /// Converts all [KnotInterpolation::BSpline] and [KnotInterpolation::HermiteRaw] segments to [KnotInterpolation::Cubic] with weighted tangents,
+    /// keeping the shape of the curve as long as the segments do not fold over themselves.
+    ///
+    /// Knots inside a B-spline run are moved onto the curve, to the points where the segments join.
+    pub(crate) fn bake_bezier_segments(&mut self) {
+        let beziers: Vec<Option<[Vec2; 4]>> = (0..self.knots.len().saturating_sub(1))
+            .map(|i| {
+                matches!(
+                    self.knots[i].interpolation,
+                    KnotInterpolation::BSpline | KnotInterpolation::HermiteRaw { .. }
+                )
+                .then(|| self.segment_bezier(i))
+            })
+            .collect();
+        for (i, bezier) in beziers.into_iter().enumerate() {
+            if let Some(bezier) = bezier {
+                let (right, left) = tangents_from_bezier(bezier);
+                self.knots[i].interpolation = KnotInterpolation::Cubic;
+                self.knots[i].position = bezier[0];
+                self.knots[i].right_tangent = right;
+                self.knots[i + 1].position = bezier[3];
+                self.knots[i + 1].left_tangent = left;
+            }
+        }
+    }
+
+    /// Converts all [KnotInterpolation::Cardinal] segments to [KnotInterpolation::Cubic] with unweighted tangents, without changing the shape of the curve
+    pub(crate) fn bake_cardinal_segments(&mut self) {
+        self.set_tension(self.tension);
+        for knot in &mut self.knots {
+            if matches!(knot.interpolation, KnotInterpolation::Cardinal) {
+                knot.interpolation = KnotInterpolation::Cubic;
+            }
+        }
+    }
+
+    /// Returns an iterator over the segments between neighbouring knots in the curve
+    pub fn segments(&self) -> impl Iterator<Item = CurveSegment<'_>> {
+        self.knots
+            .windows(2)
+            .enumerate()
+            .map(|(index, w)| CurveSegment {
+                left_knot: &w[0],
+                right_knot: &w[1],
+                interpolation: w[0].interpolation,
+                curve: self,
+                index,
+            })
+    }
+
+    /// Returns an iterator over all pairs of neighbouring knots. See [LookupCurve::segments] for the segments between them.
+    pub fn knot_windows_2(&self) -> impl Iterator<Item = (&Knot, &Knot)> {
+        self.knots.windows(2).map(|w| (&w[0], &w[1]))
+    }
+
+    /// Returns an iterator over all triples of consecutive knots, e.g. for algorithms depending on both neighbours of a knot.
+    ///
+    /// Yields nothing for curves with fewer than three knots.
+    pub fn knot_windows_3(&self) -> impl Iterator<Item = (&Knot, &Knot, &Knot)> {
+        self.knots.windows(3).map(|w| (&w[0], &w[1], &w[2]))
+    }
+
     #[inline]
     /// Given a knot index, returns the previous knot in the curve, or `None` if there is no previous knot.
     pub fn prev_knot(&self, i: usize) -> Option<&Knot> {
@@ -377,32 +1367,44 @@ impl LookupCurve {
     /// Given a knot index, returns the next knot in the curve, or `None` if there is no next knot.
     #[inline]
     pub fn next_knot(&self, i: usize) -> Option<&Knot> {
-        if i < self.knots.len() - 1 {
+        if i + 1 < self.knots.len() {
             Some(&self.knots[i + 1])
         } else {
             None
         }
     }
 
-    /// Adds a knot to the curve. Returns the index of the added knot.
-    pub fn add_knot(&mut self, knot: Knot) -> usize {
+    /// Adds a knot to the curve. Returns the index of the added knot, or `None` if the knot has a NaN position and was not added.
+    pub fn add_knot(&mut self, knot: Knot) -> Option<usize> {
+        if !is_valid_knot(&knot) {
+            return None;
+        }
         if self.knots.is_empty() || knot.position.x > self.knots.last().unwrap().position.x {
             self.knots.push(knot);
-            return self.knots.len() - 1;
+            return Some(self.knots.len() - 1);
         }
 
         let i = self
             .knots
             .partition_point(|k| k.position.x < knot.position.x);
         self.knots.insert(i, knot);
-        i
+        Some(i)
     }
 
     /// Modifies an existing knot in the lookup curve. Returns the new (possibly unchanged) index of the knot.
-    pub fn modify_knot(&mut self, i: usize, new_value: Knot) -> usize {
-        let old_value = self.knots[i];
+    ///
+    /// If the knot is [locked](Knot::locked), only the `locked` flag of `new_value` is applied.
+    /// A NaN position in `new_value` is rejected, keeping the current position of the knot.
+    pub fn modify_knot(&mut self, i: usize, mut new_value: Knot) -> usize {
+        if self.knots[i].locked {
+            self.knots[i].locked = new_value.locked;
+            return i;
+        }
+        if new_value.position.is_nan() {
+            new_value.position = self.knots[i].position;
+        }
 
-        if old_value.position.x == new_value.position.x {
+        if self.knots[i].position.x == new_value.position.x {
             // The knot has not been moved on the x axis, simply overwrite it
             self.knots[i] = new_value;
             return i;
@@ -426,12 +1428,189 @@ impl LookupCurve {
         insert_i
     }
 
-    /// Deletes a knot given index
-    pub fn delete_knot(&mut self, i: usize) {
+    /// Modifies the knot with the given [id](Knot::id), see [LookupCurve::modify_knot]. The id of the knot is kept.
+    ///
+    /// Returns the new (possibly unchanged) index of the knot, or `None` if there is no knot with the given id.
+    pub fn modify_knot_by_id(&mut self, id: usize, new_value: Knot) -> Option<usize> {
+        let i = self.knots.iter().position(|knot| knot.id == id)?;
+        Some(self.modify_knot(i, Knot { id, ..new_value }))
+    }
+
+    /// Returns mutable access to the knot at index `i`, which is moved to its sorted position when the returned guard is dropped.
+    ///
+    /// Useful for changing single fields, e.g. `curve.get_knot_mut(i).position.y = 0.5`.
+    pub fn get_knot_mut(&mut self, i: usize) -> KnotMutGuard<'_> {
+        let original_position = self.knots[i].position;
+        let locked_original = self.knots[i].locked.then(|| self.knots[i].clone());
+        KnotMutGuard {
+            curve: self,
+            index: i,
+            original_position,
+            locked_original,
+        }
+    }
+
+    /// Returns mutable access to all knots at once, which are sorted by x-position when the returned guard is dropped.
+    ///
+    /// Avoids re-sorting after every change when modifying many knots, e.g. when generating curves procedurally.
+    /// As with [LookupCurve::with_knots], knots must not share the same x-position (within [LookupCurve::KNOT_X_EPSILON]), which is checked in debug builds.
+    pub fn begin_bulk_edit(&mut self) -> BulkEdit<'_> {
+        let locked_knots = self
+            .knots
+            .iter()
+            .filter(|knot| knot.locked)
+            .cloned()
+            .collect();
+        BulkEdit {
+            curve: self,
+            locked_knots,
+        }
+    }
+
+    /// Modifies an existing knot in place using the supplied closure, see [LookupCurve::get_knot_mut]. Returns the new (possibly unchanged) index of the knot.
+    ///
+    /// If the knot is [locked](Knot::locked), only changes to the `locked` flag are applied. A NaN position is rejected, keeping the previous position.
+    pub fn modify_knot_inplace(&mut self, i: usize, f: impl FnOnce(&mut Knot)) -> usize {
+        if self.knots[i].locked {
+            let mut knot = self.knots[i].clone();
+            f(&mut knot);
+            self.knots[i].locked = knot.locked;
+            return i;
+        }
+
+        let position = self.knots[i].position;
+        f(&mut self.knots[i]);
+        if self.knots[i].position.is_nan() {
+            self.knots[i].position = position;
+        }
+        if self.knots[i].position.x == position.x {
+            return i;
+        }
+        self.resort_knot(i)
+    }
+
+    /// Moves the knot at index `i` to its sorted position. Returns the new index of the knot. Expects the knot to not have a NaN position.
+    fn resort_knot(&mut self, i: usize) -> usize {
+        let knot = self.knots.remove(i);
+        let x = knot.position.x;
+        let i = self.knots.partition_point(|k| k.position.x < x);
+        self.knots.insert(i, knot);
+        i
+    }
+
+    /// Modifies an existing knot like [LookupCurve::modify_knot], then recomputes the tangent slopes of the knot and its immediate neighbours using [LookupCurve::cardinal_slope].
+    ///
+    /// Only unlocked knots where both tangents are [TangentMode::Aligned] are updated. Returns the new (possibly unchanged) index of the knot.
+    pub fn modify_knot_smooth(&mut self, i: usize, new_value: Knot) -> usize {
+        let i = self.modify_knot(i, new_value);
+
+        let last = self.knots.len() - 1;
+        for j in i.saturating_sub(1)..=(i + 1).min(last) {
+            let knot = &self.knots[j];
+            if !knot.locked
+                && matches!(
+                    (knot.left_tangent.mode, knot.right_tangent.mode),
+                    (TangentMode::Aligned, TangentMode::Aligned)
+                )
+            {
+                let slope = self.cardinal_slope(j);
+                self.knots[j] = self.knots[j].with_tangent_slope(TangentSide::Left, slope);
+            }
+        }
+
+        i
+    }
+
+    /// Computes a Catmull-Rom tangent slope for the knot at index `i`, based on the positions of its neighbouring knots.
+    ///
+    /// The first and last knots use the slope towards their only neighbour.
+    pub fn catmull_rom_slope(&self, i: usize) -> f32 {
+        let prev = self.prev_knot(i).unwrap_or(&self.knots[i]).position;
+        let next = self.next_knot(i).unwrap_or(&self.knots[i]).position;
+        let dx = next.x - prev.x;
+        if dx == 0.0 {
+            0.0
+        } else {
+            (next.y - prev.y) / dx
+        }
+    }
+
+    /// Computes the slope of a Cardinal spline at the knot with index `i`, which is the [LookupCurve::catmull_rom_slope] scaled by the [LookupCurve::tension].
+    ///
+    /// A tension of 0.5 gives a Catmull-Rom spline. Values towards 1.0 give tighter curves with less overshoot, and values towards 0.0 give looser curves.
+    pub fn cardinal_slope(&self, i: usize) -> f32 {
+        self.catmull_rom_slope(i) * 2.0 * (1.0 - self.tension)
+    }
+
+    /// Sets the [LookupCurve::tension] and recomputes the tangents of all [KnotInterpolation::Cardinal] segments using [LookupCurve::cardinal_slope].
+    ///
+    /// The tangents are kept in sync so that the shape stays the same when switching a knot to [KnotInterpolation::Cubic].
+    pub fn set_tension(&mut self, tension: f32) {
+        self.tension = tension;
+
+        let slopes: Vec<f32> = (0..self.knots.len())
+            .map(|i| self.cardinal_slope(i))
+            .collect();
+        for i in 0..self.knots.len().saturating_sub(1) {
+            if matches!(self.knots[i].interpolation, KnotInterpolation::Cardinal) {
+                self.knots[i].right_tangent.slope = slopes[i];
+                self.knots[i].right_tangent.weight = None;
+                self.knots[i + 1].left_tangent.slope = slopes[i + 1];
+                self.knots[i + 1].left_tangent.weight = None;
+            }
+        }
+    }
+
+    /// Returns a clone of the knot with index `i` with the interpolation changed to `interpolation`, keeping the curve visually continuous when switching back and forth.
+    ///
+    /// When switching to [KnotInterpolation::Cubic], `saved_tangents` (left, right) stored when the knot was switched away from it are restored.
+    /// The left tangent is only restored if the previous segment is not cubic, as it might have been edited since.
+    /// Without saved tangents and with both slopes at zero, the slopes are computed using [LookupCurve::cardinal_slope] instead.
+    pub fn knot_with_interpolation(
+        &self,
+        i: usize,
+        interpolation: KnotInterpolation,
+        saved_tangents: Option<(Tangent, Tangent)>,
+    ) -> Knot {
+        let knot = &self.knots[i];
+        let mut new_knot = Knot {
+            interpolation,
+            ..knot.clone()
+        };
+
+        let was_cubic = matches!(knot.interpolation, KnotInterpolation::Cubic);
+        let is_cubic = matches!(interpolation, KnotInterpolation::Cubic);
+        if !was_cubic && is_cubic {
+            if let Some((left, right)) = saved_tangents {
+                let prev_cubic = self
+                    .prev_knot(i)
+                    .is_some_and(|k| matches!(k.interpolation, KnotInterpolation::Cubic));
+                if !prev_cubic {
+                    new_knot.left_tangent = left;
+                }
+                new_knot.right_tangent = right;
+            } else if knot.left_tangent.slope == 0.0 && knot.right_tangent.slope == 0.0 {
+                let slope = self.cardinal_slope(i);
+                new_knot.left_tangent.slope = slope;
+                new_knot.right_tangent.slope = slope;
+            }
+        }
+
+        new_knot
+    }
+
+    /// Deletes a knot given index. Returns `false` if the knot is [locked](Knot::locked), in which case it is not deleted.
+    pub fn delete_knot(&mut self, i: usize) -> bool {
+        if self.knots[i].locked {
+            return false;
+        }
         self.knots.remove(i);
+        true
     }
 
     /// Find y for given x on the curve
+    ///
+    /// A curve without knots always returns `0.0`, and a curve with a single knot always returns the y-value of that knot.
     #[inline]
     pub fn lookup(&self, x: f32) -> f32 {
         self.lookup_internal(x, None)
@@ -443,54 +1622,207 @@ impl LookupCurve {
         self.lookup_internal(x, Some(cache))
     }
 
+    /// Find y for each x in `xs`, writing the results to `out`. Uses a [LookupCache], which speeds up evaluation of sorted or coherent x-values.
+    ///
+    /// # Panics
+    /// Panics if `xs` and `out` have different lengths.
+    pub fn lookup_batch(&self, xs: &[f32], out: &mut [f32]) {
+        assert_eq!(xs.len(), out.len(), "xs and out must have the same length");
+
+        let mut cache = LookupCache::new();
+        for (x, y) in xs.iter().zip(out) {
+            *y = self.lookup_cached(*x, &mut cache);
+        }
+    }
+
     #[inline]
     fn lookup_internal(&self, x: f32, cache: Option<&mut LookupCache>) -> f32 {
-        // Return repeated constant values outside of knot range
-        if self.knots.is_empty() {
-            return 0.0;
+        let y = self.lookup_unclamped(x, cache);
+        match self.output_clamp {
+            Some((min, max)) => y.max(min).min(max),
+            None => y,
         }
-        if self.knots.len() == 1 || x <= self.knots[0].position.x {
-            return self.knots[0].position.y;
+    }
+
+    #[inline]
+    fn lookup_unclamped(&self, x: f32, cache: Option<&mut LookupCache>) -> f32 {
+        match self.segment_index(x, cache) {
+            Some(i) => self.interpolate(i, x),
+            None => self.outside_value(x),
         }
-        if x >= self.knots[self.knots.len() - 1].position.x {
-            return self.knots[self.knots.len() - 1].position.y;
+    }
+
+    /// Find y and the derivative dy/dx for given x on the curve
+    ///
+    /// The derivative is zero outside of the knot range, and where the output is clamped by [LookupCurve::output_clamp].
+    /// Curves with fewer than two knots are constant, so their derivative is always zero.
+    #[inline]
+    pub fn lookup_with_derivative(&self, x: f32) -> (f32, f32) {
+        self.lookup_with_derivative_internal(x, None)
+    }
+
+    /// Find y and the derivative dy/dx for given x on the curve, with a LookupCache. See [LookupCurve::lookup_with_derivative] and [LookupCurve::lookup_cached].
+    #[inline]
+    pub fn lookup_with_derivative_cached(&self, x: f32, cache: &mut LookupCache) -> (f32, f32) {
+        self.lookup_with_derivative_internal(x, Some(cache))
+    }
+
+    #[inline]
+    fn lookup_with_derivative_internal(
+        &self,
+        x: f32,
+        cache: Option<&mut LookupCache>,
+    ) -> (f32, f32) {
+        let (y, dy_dx) = match self.segment_index(x, cache) {
+            Some(i) => self.interpolate_with_derivative(i, x),
+            None => (self.outside_value(x), 0.0),
+        };
+        match self.output_clamp {
+            Some((min, _)) if y < min => (min, 0.0),
+            Some((_, max)) if y > max => (max, 0.0),
+            _ => (y, dy_dx),
+        }
+    }
+
+    /// Finds the index of the left knot of the segment containing `x`, or `None` if `x` is outside of the knot range
+    #[inline]
+    fn segment_index(&self, x: f32, cache: Option<&mut LookupCache>) -> Option<usize> {
+        if self.knots.len() < 2
+            || x <= self.knots[0].position.x
+            || x >= self.knots[self.knots.len() - 1].position.x
+        {
+            return None;
         }
 
         // Find left knot
-        let i = if let Some(cache) = cache {
+        Some(if let Some(cache) = cache {
+            if let Some(i) = cache
+                .last_knot_index
+                .filter(|i| cache.hysteresis > 0.0 && i + 1 < self.knots.len())
+            {
+                let (start, end) = (self.knots[i].position.x, self.knots[i + 1].position.x);
+                if x >= start - cache.hysteresis && x <= end + cache.hysteresis {
+                    return Some(i);
+                }
+            }
             self.knots
                 .search_knots_with_cache(x, &mut cache.last_knot_index)
         } else {
             self.knots.search_knots(x)
-        };
-        let knot_a = self.knots[i];
+        })
+    }
+
+    /// Value of the curve outside of the knot range, repeating the first or last knot
+    #[inline]
+    fn outside_value(&self, x: f32) -> f32 {
+        match (self.knots.first(), self.knots.last()) {
+            (Some(first), _) if self.knots.len() == 1 || x <= first.position.x => first.position.y,
+            (_, Some(last)) => last.position.y,
+            _ => 0.0,
+        }
+    }
 
-        // Interpolate
+    /// Interpolates within the segment starting at knot `i`. Expects `x` to be within the segment.
+    #[inline]
+    fn interpolate(&self, i: usize, x: f32) -> f32 {
+        let (knot_a, knot_b) = (&self.knots[i], &self.knots[i + 1]);
         match knot_a.interpolation {
             KnotInterpolation::Constant => knot_a.position.y,
             KnotInterpolation::Linear => {
-                let knot_b = &self.knots[i + 1];
                 let s = (x - knot_a.position.x) / (knot_b.position.x - knot_a.position.x);
                 knot_a.position.lerp(knot_b.position, s).y
             }
             KnotInterpolation::Cubic => {
-                let knot_b = &self.knots[i + 1];
                 if knot_a.right_tangent.weight.is_some() || knot_b.left_tangent.weight.is_some() {
-                    weighted_cubic_interp(&knot_a, knot_b, x, self.max_error, self.max_iters)
+                    weighted_cubic_interp(
+                        knot_a,
+                        knot_b,
+                        x,
+                        self.max_error,
+                        self.max_iters,
+                        self.cubic_solver,
+                    )
+                } else {
+                    hermite_interp(
+                        knot_a.position,
+                        knot_b.position,
+                        knot_a.right_tangent.slope,
+                        knot_b.left_tangent.slope,
+                        x,
+                    )
+                }
+            }
+            KnotInterpolation::Cardinal => hermite_interp(
+                knot_a.position,
+                knot_b.position,
+                self.cardinal_slope(i),
+                self.cardinal_slope(i + 1),
+                x,
+            ),
+            KnotInterpolation::BSpline | KnotInterpolation::HermiteRaw { .. } => self
+                .bezier_segment_at(i, x)
+                .find_y_given_x(x, self.max_error, self.max_iters, self.cubic_solver),
+        }
+    }
+
+    /// Like [LookupCurve::interpolate], but also returns the derivative dy/dx
+    #[inline]
+    fn interpolate_with_derivative(&self, i: usize, x: f32) -> (f32, f32) {
+        let (knot_a, knot_b) = (&self.knots[i], &self.knots[i + 1]);
+        match knot_a.interpolation {
+            KnotInterpolation::Constant => (knot_a.position.y, 0.0),
+            KnotInterpolation::Linear => {
+                let delta = knot_b.position - knot_a.position;
+                let s = (x - knot_a.position.x) / delta.x;
+                (knot_a.position.y + delta.y * s, delta.y / delta.x)
+            }
+            KnotInterpolation::Cubic => {
+                if knot_a.right_tangent.weight.is_some() || knot_b.left_tangent.weight.is_some() {
+                    let segment =
+                        CubicSegment::from_bezier_points(knot_a.compute_bezier_to(knot_b));
+                    let (t, position) = segment.find_t_given_x(
+                        x,
+                        self.max_error,
+                        self.max_iters,
+                        self.cubic_solver,
+                    );
+                    let velocity = segment.velocity(t);
+                    (position.y, velocity.y / velocity.x)
                 } else {
-                    unweighted_cubic_interp(&knot_a, knot_b, x)
+                    hermite_interp_with_derivative(
+                        knot_a.position,
+                        knot_b.position,
+                        knot_a.right_tangent.slope,
+                        knot_b.left_tangent.slope,
+                        x,
+                    )
                 }
             }
+            KnotInterpolation::Cardinal => hermite_interp_with_derivative(
+                knot_a.position,
+                knot_b.position,
+                self.cardinal_slope(i),
+                self.cardinal_slope(i + 1),
+                x,
+            ),
+            KnotInterpolation::BSpline | KnotInterpolation::HermiteRaw { .. } => {
+                let segment = self.bezier_segment_at(i, x);
+                let (t, position) =
+                    segment.find_t_given_x(x, self.max_error, self.max_iters, self.cubic_solver);
+                let velocity = segment.velocity(t);
+                (position.y, velocity.y / velocity.x)
+            }
         }
     }
 }
 
 #[inline]
-fn unweighted_cubic_interp(knot_a: &Knot, knot_b: &Knot, x: f32) -> f32 {
-    let x = (x - knot_a.position.x) / (knot_b.position.x - knot_a.position.x);
-    let dx = knot_b.position.x - knot_a.position.x;
-    let m0 = knot_a.right_tangent.slope * dx;
-    let m1 = knot_b.left_tangent.slope * dx;
+/// Cubic hermite interpolation between `p0` and `p1` with the given slopes, used for unweighted cubic segments
+fn hermite_interp(p0: Vec2, p1: Vec2, slope0: f32, slope1: f32, x: f32) -> f32 {
+    let x = (x - p0.x) / (p1.x - p0.x);
+    let dx = p1.x - p0.x;
+    let m0 = slope0 * dx;
+    let m1 = slope1 * dx;
 
     let x2 = x * x;
     let x3 = x2 * x;
@@ -500,7 +1832,35 @@ fn unweighted_cubic_interp(knot_a: &Knot, knot_b: &Knot, x: f32) -> f32 {
     let c = x3 - x2;
     let d = -2. * x3 + 3. * x2;
 
-    a * knot_a.position.y + b * m0 + c * m1 + d * knot_b.position.y
+    a * p0.y + b * m0 + c * m1 + d * p1.y
+}
+
+/// Derivative of the hermite polynomial in [hermite_interp], returned together with the value
+#[inline]
+fn hermite_interp_with_derivative(
+    p0: Vec2,
+    p1: Vec2,
+    slope0: f32,
+    slope1: f32,
+    x: f32,
+) -> (f32, f32) {
+    let dx = p1.x - p0.x;
+    let s = (x - p0.x) / dx;
+    let m0 = slope0 * dx;
+    let m1 = slope1 * dx;
+
+    let s2 = s * s;
+
+    // d/ds of the basis functions
+    let a = 6. * s2 - 6. * s;
+    let b = 3. * s2 - 4. * s + 1.;
+    let c = 3. * s2 - 2. * s;
+    let d = -6. * s2 + 6. * s;
+
+    let dy_ds = a * p0.y + b * m0 + c * m1 + d * p1.y;
+
+    // chain rule, ds/dx = 1 / dx
+    (hermite_interp(p0, p1, slope0, slope1, x), dy_ds / dx)
 }
 
 #[inline]
@@ -510,9 +1870,10 @@ fn weighted_cubic_interp(
     x: f32,
     max_error: f32,
     max_iters: u8,
+    solver: WeightedCubicSolver,
 ) -> f32 {
     CubicSegment::from_bezier_points(knot_a.compute_bezier_to(knot_b))
-        .find_y_given_x(x, max_error, max_iters)
+        .find_y_given_x(x, max_error, max_iters, solver)
 }
 
 /// Mostly a copy of code from https://github.com/bevyengine/bevy/blob/main/crates/bevy_math/src/cubic_splines.rs
@@ -520,7 +1881,7 @@ fn weighted_cubic_interp(
 /// Copied because the cubic_splines module does not exactly fit the API we need:
 /// 1. Allow constructing a single CubicSegment from bezier points (without allocating a CubicCurve, and without restricting c0 and c1 to 0 and 1)
 /// 2. find_y_given_x needs to be accessible
-/// 3. max_iters and max_error should be configurable
+/// 3. max_iters, max_error and the solving strategy should be configurable
 #[derive(Clone, Debug, Default, PartialEq)]
 struct CubicSegment {
     coeff: [Vec2; 4],
@@ -542,10 +1903,41 @@ impl CubicSegment {
     }
 
     #[inline]
-    fn find_y_given_x(&self, x: f32, max_error: f32, max_iters: u8) -> f32 {
-        let mut t_guess = x;
+    fn find_y_given_x(
+        &self,
+        x: f32,
+        max_error: f32,
+        max_iters: u8,
+        solver: WeightedCubicSolver,
+    ) -> f32 {
+        self.find_t_given_x(x, max_error, max_iters, solver).1.y
+    }
+
+    /// Finds the parametric value `t` given `x`, returned together with the position at `t`
+    #[inline]
+    fn find_t_given_x(
+        &self,
+        x: f32,
+        max_error: f32,
+        max_iters: u8,
+        solver: WeightedCubicSolver,
+    ) -> (f32, Vec2) {
+        let mut t_guess = match solver {
+            WeightedCubicSolver::NewtonRaphsonFromX => {
+                let start_x = self.coeff[0].x;
+                let end_x = self.position(1.0).x;
+                (x - start_x) / (end_x - start_x)
+            }
+            WeightedCubicSolver::NewtonRaphsonFromMidpoint => 0.5,
+            WeightedCubicSolver::Bisection => {
+                let t = self.find_t_given_x_bisection(x, max_error, max_iters);
+                return (t, self.position(t));
+            }
+        };
+        let mut t_pos = t_guess;
         let mut pos_guess = Vec2::ZERO;
         for _ in 0..max_iters {
+            t_pos = t_guess;
             pos_guess = self.position(t_guess);
             let error = pos_guess.x - x;
             if error.abs() <= max_error {
@@ -555,7 +1947,27 @@ impl CubicSegment {
             let slope = self.velocity(t_guess).x; // dx/dt
             t_guess -= error / slope;
         }
-        pos_guess.y
+        (t_pos, pos_guess)
+    }
+
+    /// Finds the parametric value `t` given `x` using binary search. Expects the segment to be monotonic in x.
+    #[inline]
+    fn find_t_given_x_bisection(&self, x: f32, max_error: f32, max_iters: u8) -> f32 {
+        let (mut t_min, mut t_max) = (0.0, 1.0);
+        let mut t_guess = 0.5;
+        for _ in 0..max_iters {
+            t_guess = (t_min + t_max) * 0.5;
+            let error = self.position(t_guess).x - x;
+            if error.abs() <= max_error {
+                break;
+            }
+            if error < 0.0 {
+                t_min = t_guess;
+            } else {
+                t_max = t_guess;
+            }
+        }
+        t_guess
     }
 
     #[inline]
@@ -585,3 +1997,391 @@ impl CubicSegment {
         CubicSegment { coeff }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nan_positions_are_rejected() {
+        let mut curve =
+            LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)]).unwrap();
+        assert_eq!(curve.add_knot(Knot::at(f32::NAN, 0.5)), None);
+        assert_eq!(curve.add_knot(Knot::at(0.5, f32::NAN)), None);
+        assert_eq!(curve.modify_knot(1, Knot::at(f32::NAN, 0.5)), 1);
+        assert_eq!(curve.knots()[1].position, Vec2::new(1.0, 1.0));
+        curve.modify_knot_inplace(0, |knot| knot.position.x = f32::NAN);
+        curve.get_knot_mut(0).position.y = f32::NAN;
+        assert_eq!(curve.knots().len(), 2);
+        assert_eq!(curve.knots()[0].position, Vec2::ZERO);
+        assert_eq!(curve.add_knot(Knot::at(-1.0, 0.5)), Some(0));
+
+        // A knot with a NaN y-position is removed, so it is not a duplicate
+        let curve =
+            LookupCurve::with_knots(vec![Knot::at(0.5, f32::NAN), Knot::at(0.5, 1.0)]).unwrap();
+        assert_eq!(curve.knots().len(), 1);
+    }
+
+    #[test]
+    fn locked_knots_ignore_mutation() {
+        let locked = Knot {
+            locked: true,
+            ..Knot::at(0.5, 0.5)
+        };
+        let mut curve =
+            LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), locked, Knot::at(1.0, 1.0)]).unwrap();
+
+        let moved = Knot {
+            locked: true,
+            ..Knot::at(0.8, 0.0)
+        };
+        assert_eq!(curve.modify_knot(1, moved), 1);
+        curve.modify_knot_inplace(1, |knot| knot.position.y = 0.0);
+        curve.get_knot_mut(1).position = Vec2::new(0.2, 0.0);
+        curve
+            .begin_bulk_edit()
+            .knots_mut()
+            .retain(|knot| !knot.locked);
+        curve.begin_bulk_edit().knots_mut()[1].position.y = 0.0;
+        assert!(!curve.delete_knot(1));
+        assert_eq!(curve.knots().len(), 3);
+        assert_eq!(curve.knots()[1].position, Vec2::new(0.5, 0.5));
+
+        // Unlocking is applied, after which the knot can be modified
+        let index = curve.modify_knot(
+            1,
+            Knot {
+                locked: false,
+                ..Knot::at(0.8, 0.0)
+            },
+        );
+        assert_eq!(index, 1);
+        assert!(!curve.knots()[1].locked);
+        assert_eq!(curve.knots()[1].position, Vec2::new(0.5, 0.5));
+        curve.modify_knot(1, Knot::at(0.8, 0.0));
+        assert_eq!(curve.knots()[1].position, Vec2::new(0.8, 0.0));
+        assert!(curve.delete_knot(1));
+        assert_eq!(curve.knots().len(), 2);
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn annotation_round_trips() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                annotation: Some("Peak \"impact\"".to_string()),
+                ..Knot::at(0.0, 0.0)
+            },
+            Knot::at(1.0, 1.0),
+        ])
+        .unwrap();
+
+        let saved = curve.to_ron_string().unwrap();
+        let loaded = LookupCurve::from_ron_bytes(saved.as_bytes()).unwrap();
+        assert_eq!(
+            loaded.knots()[0].annotation.as_deref(),
+            Some("Peak \"impact\"")
+        );
+        assert_eq!(loaded.knots()[1].annotation, None);
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn anchor_round_trips() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                anchor: true,
+                ..Knot::at(0.0, 0.0)
+            },
+            Knot::at(1.0, 1.0),
+        ])
+        .unwrap();
+
+        let saved = curve.to_ron_string().unwrap();
+        assert_eq!(saved.matches("anchor: true").count(), 1);
+        let loaded = LookupCurve::from_ron_bytes(saved.as_bytes()).unwrap();
+        assert!(loaded.knots()[0].anchor);
+        assert!(!loaded.knots()[1].anchor);
+    }
+
+    #[test]
+    fn step_function_holds_values() {
+        let curve = LookupCurve::from_step_function(&[(0.5, 1.0), (0.0, 0.0), (0.75, 2.0)]);
+        assert_eq!(curve.knots().len(), 3);
+        assert_eq!(curve.lookup(0.25), 0.0);
+        assert_eq!(curve.lookup(0.6), 1.0);
+        assert_eq!(curve.lookup(0.8), 2.0);
+        assert_eq!(curve.lookup(100.0), 2.0);
+        assert_eq!(curve.lookup(-1.0), 0.0);
+        assert_eq!(
+            LookupCurve::from_step_function(&[(0.0, 0.0), (0.5, 1.0), (0.75, 2.0)]).lookup(0.6),
+            1.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "distinct x_start")]
+    fn step_function_rejects_duplicate_steps() {
+        LookupCurve::from_step_function(&[(0.0, 0.0), (0.5, 1.0), (0.5, 2.0)]);
+    }
+
+    #[test]
+    fn set_tension_updates_cardinal_tangents() {
+        let mut curve = LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.0)
+                .with_interpolation(KnotInterpolation::Cardinal)
+                .with_weights(None, Some(0.5)),
+            Knot::at(1.0, 1.0)
+                .with_interpolation(KnotInterpolation::Cubic)
+                .with_slope(3.0),
+            Knot::at(2.0, 0.0),
+        ])
+        .unwrap();
+
+        curve.set_tension(1.0);
+        assert_eq!(curve.tension, 1.0);
+        assert_eq!(curve.knots()[0].right_tangent.slope, 0.0);
+        assert_eq!(curve.knots()[0].right_tangent.weight, None);
+        assert_eq!(curve.knots()[1].left_tangent.slope, 0.0);
+        // Tangents of other segments are left alone
+        assert_eq!(curve.knots()[1].right_tangent.slope, 3.0);
+
+        curve.set_tension(0.0);
+        assert_eq!(curve.knots()[0].right_tangent.slope, 2.0);
+        assert_eq!(curve.knots()[1].left_tangent.slope, 0.0);
+
+        // The tangents match the cardinal segment, so switching to cubic keeps the shape
+        let mut cubic = curve.clone();
+        cubic.knots[0].interpolation = KnotInterpolation::Cubic;
+        for i in 0..=20 {
+            let x = i as f32 / 20.0;
+            assert!((curve.lookup(x) - cubic.lookup(x)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn bulk_edit_sorts_on_drop() {
+        let mut curve =
+            LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)]).unwrap();
+        {
+            let mut edit = curve.begin_bulk_edit();
+            let knots = edit.knots_mut();
+            knots.push(Knot::at(0.5, 2.0));
+            knots.push(Knot::at(-1.0, 0.0));
+            knots[0].position.x = 2.0;
+        }
+        let xs: Vec<f32> = curve.knots().iter().map(|knot| knot.position.x).collect();
+        assert_eq!(xs, [-1.0, 0.5, 1.0, 2.0]);
+        assert_eq!(curve.lookup(0.5), 2.0);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "sharing the same x-position")]
+    fn bulk_edit_rejects_duplicate_x() {
+        let mut curve =
+            LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)]).unwrap();
+        curve.begin_bulk_edit().knots_mut().push(Knot::at(1.0, 0.5));
+    }
+
+    #[test]
+    fn weighted_cubic_solvers_agree() {
+        // The initial guess used before solvers were configurable
+        let previous_lookup = |knot_a: &Knot, knot_b: &Knot, x: f32| {
+            let segment = CubicSegment::from_bezier_points(knot_a.compute_bezier_to(knot_b));
+            let mut t_guess = x;
+            let mut pos_guess = Vec2::ZERO;
+            for _ in 0..max_iters_default() {
+                pos_guess = segment.position(t_guess);
+                let error = pos_guess.x - x;
+                if error.abs() <= max_error_default() {
+                    break;
+                }
+                t_guess -= error / segment.velocity(t_guess).x;
+            }
+            pos_guess.y
+        };
+
+        let curve = LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.0)
+                .with_interpolation(KnotInterpolation::Cubic)
+                .with_slope(3.0)
+                .with_weights(None, Some(0.9)),
+            Knot::at(1.0, 1.0)
+                .with_slope(0.0)
+                .with_weights(Some(0.1), None),
+        ])
+        .unwrap();
+        for i in 0..=100 {
+            let x = i as f32 / 100.0;
+            let previous = previous_lookup(&curve.knots()[0], &curve.knots()[1], x);
+            assert!((curve.lookup(x) - previous).abs() < 1e-3, "x = {x}");
+        }
+
+        for solver in [
+            WeightedCubicSolver::NewtonRaphsonFromMidpoint,
+            WeightedCubicSolver::Bisection,
+        ] {
+            let other = curve.clone().with_cubic_solver(solver);
+            for i in 0..=100 {
+                let x = i as f32 / 100.0;
+                assert!(
+                    (curve.lookup(x) - other.lookup(x)).abs() < 1e-3,
+                    "{solver:?} at {x}"
+                );
+            }
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn modify_knot_by_id_keeps_knots_sorted(
+            xs in proptest::collection::vec(-100.0_f32..100.0, 1..16),
+            modifications in proptest::collection::vec((0_usize..16, -150.0_f32..150.0), 1..32),
+        ) {
+            let mut curve =
+                LookupCurve::new_unchecked(xs.into_iter().map(|x| Knot::at(x, 0.0)).collect());
+            let ids: Vec<usize> = curve.knots().iter().map(|knot| knot.id).collect();
+
+            for (i, x) in modifications {
+                let id = ids[i % ids.len()];
+                let new_value = Knot::at(x, 1.0);
+                let new_i = curve.modify_knot_by_id(id, new_value).unwrap();
+
+                proptest::prop_assert_eq!(curve.knots()[new_i].id, id);
+                proptest::prop_assert_eq!(curve.knots()[new_i].position.x, x);
+                proptest::prop_assert!(curve
+                    .knots()
+                    .windows(2)
+                    .all(|w| w[0].position.x <= w[1].position.x));
+            }
+            proptest::prop_assert_eq!(curve.modify_knot_by_id(usize::MAX, Knot::default()), None);
+        }
+    }
+
+    #[test]
+    fn knot_windows_overlap() {
+        let curve =
+            LookupCurve::with_knots((0..4).map(|i| Knot::at(i as f32, 0.0)).collect()).unwrap();
+
+        let pairs: Vec<(f32, f32)> = curve
+            .knot_windows_2()
+            .map(|(a, b)| (a.position.x, b.position.x))
+            .collect();
+        assert_eq!(pairs, [(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]);
+
+        let triples: Vec<(f32, f32, f32)> = curve
+            .knot_windows_3()
+            .map(|(a, b, c)| (a.position.x, b.position.x, c.position.x))
+            .collect();
+        assert_eq!(triples, [(0.0, 1.0, 2.0), (1.0, 2.0, 3.0)]);
+
+        assert_eq!(LookupCurve::default().knot_windows_2().count(), 0);
+        let single = LookupCurve::with_knots(vec![Knot::at(1.0, 2.0)]).unwrap();
+        assert_eq!(single.knot_windows_2().count(), 0);
+        let pair = LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)]).unwrap();
+        assert_eq!(pair.knot_windows_3().count(), 0);
+    }
+
+    #[cfg(feature = "bevy_app")]
+    #[test]
+    fn changed_filter_fires_once_per_modification() {
+        use bevy_app::{App, Update};
+        use bevy_ecs::prelude::{Changed, Query, ResMut, Resource};
+
+        #[derive(Resource, Default)]
+        struct ChangedCount(usize);
+
+        fn count_changed(query: Query<(), Changed<LookupCurve>>, mut count: ResMut<ChangedCount>) {
+            count.0 += query.iter().count();
+        }
+
+        let mut app = App::new();
+        app.init_resource::<ChangedCount>()
+            .add_systems(Update, count_changed);
+        let entity = app
+            .world_mut()
+            .spawn(LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)]).unwrap())
+            .id();
+
+        // Spawning counts as a change
+        app.update();
+        assert_eq!(app.world().resource::<ChangedCount>().0, 1);
+
+        app.update();
+        assert_eq!(app.world().resource::<ChangedCount>().0, 1);
+
+        let mut curve = app.world_mut().get_mut::<LookupCurve>(entity).unwrap();
+        let knot = curve.knots()[1].clone();
+        curve.modify_knot(1, Knot::at(1.0, 0.5).with_interpolation(knot.interpolation));
+        app.update();
+        assert_eq!(app.world().resource::<ChangedCount>().0, 2);
+
+        // Reading without mutating does not trigger change detection
+        let _ = app.world().get::<LookupCurve>(entity).unwrap().lookup(0.5);
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<ChangedCount>().0, 2);
+    }
+
+    #[test]
+    fn nan_knots_are_removed() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot::at(1.0, 1.0),
+            Knot::at(f32::NAN, 0.5),
+            Knot::at(0.0, 0.0),
+            Knot::at(0.5, f32::NAN),
+            Knot::at(-f32::NAN, 0.0),
+        ])
+        .unwrap();
+        assert_eq!(curve.knots().len(), 2);
+        assert_eq!(curve.domain(), (0.0, 1.0));
+        assert_eq!(curve.lookup(0.5), 0.5);
+
+        let mut curve =
+            LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)]).unwrap();
+        curve
+            .begin_bulk_edit()
+            .knots_mut()
+            .insert(0, Knot::at(f32::NAN, 0.0));
+        assert_eq!(curve.knots().len(), 2);
+        assert_eq!(curve.lookup(0.25), 0.25);
+    }
+
+    #[test]
+    fn endpoint_slopes() {
+        let knots = vec![
+            Knot::at(0.0, 0.0).with_interpolation(KnotInterpolation::Cubic),
+            Knot::at(1.0, 1.0)
+                .with_interpolation(KnotInterpolation::Cubic)
+                .with_slope(0.5),
+            Knot::at(2.0, 0.5),
+        ];
+
+        let clamped = LookupCurve::with_knots(knots.clone())
+            .unwrap()
+            .with_endpoint_slopes(2.0, -1.0);
+        assert!((clamped.lookup_with_derivative(1e-4).1 - 2.0).abs() < 1e-2);
+        assert!((clamped.lookup_with_derivative(2.0 - 1e-4).1 + 1.0).abs() < 1e-2);
+
+        // Second derivative estimated by finite differences
+        let natural = LookupCurve::with_knots(knots)
+            .unwrap()
+            .with_natural_endpoints();
+        let h = 1e-2;
+        let curvature = |x: f32| {
+            (natural.lookup(x + h) - 2.0 * natural.lookup(x) + natural.lookup(x - h)) / (h * h)
+        };
+        assert!(curvature(h).abs() < 0.1);
+        assert!(curvature(2.0 - h).abs() < 0.1);
+        assert!(curvature(1.0 - h).abs() > 0.5);
+
+        let line = LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.0).with_interpolation(KnotInterpolation::Cubic),
+            Knot::at(2.0, 1.0),
+        ])
+        .unwrap()
+        .with_natural_endpoints();
+        assert!((line.lookup(0.5) - 0.25).abs() < 1e-5);
+    }
+}
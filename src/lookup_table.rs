@@ -0,0 +1,118 @@
+use crate::{knot_search::uniform_index, LookupCache, LookupCurve};
+
+/// Lookup table of y-values sampled at uniformly spaced x-values, with `O(1)` lookups.
+///
+/// Bake one from a curve with [LookupCurve::bake_uniform]. Lookups interpolate linearly between the samples,
+/// and x-values outside of the table are clamped to its range.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct UniformBakedLookupTable {
+    /// x-value of the first sample
+    pub x0: f32,
+    /// Distance along the x-axis between samples
+    pub step: f32,
+    /// Sampled y-values
+    pub ys: Vec<f32>,
+}
+
+impl UniformBakedLookupTable {
+    pub fn new(x0: f32, step: f32, ys: Vec<f32>) -> Self {
+        Self { x0, step, ys }
+    }
+
+    /// Find y for given x, interpolating linearly between the two nearest samples
+    ///
+    /// A table without samples returns `0.0`. A table with a single sample, or a `step` of zero (baked from a single knot), returns the first sample.
+    #[inline]
+    pub fn lookup(&self, x: f32) -> f32 {
+        match self.ys.len() {
+            0 => 0.0,
+            _ if self.ys.len() == 1 || self.step == 0.0 => self.ys[0],
+            len => {
+                let i = uniform_index(len, x, self.x0, self.step);
+                let t = ((x - self.x0) / self.step - i as f32).clamp(0.0, 1.0);
+                self.ys[i] + (self.ys[i + 1] - self.ys[i]) * t
+            }
+        }
+    }
+}
+
+impl LookupCurve {
+    /// Bakes the curve into a [UniformBakedLookupTable] with `count` samples uniformly spaced over the domain of the curve.
+    ///
+    /// Trades accuracy for speed, lookups in the table are `O(1)` regardless of the number of knots.
+    pub fn bake_uniform(&self, count: usize) -> UniformBakedLookupTable {
+        let (min_x, max_x) = self.domain();
+        let step = (max_x - min_x) / count.saturating_sub(1).max(1) as f32;
+
+        let mut cache = LookupCache::new();
+        let ys = (0..count)
+            .map(|i| self.lookup_cached(min_x + step * i as f32, &mut cache))
+            .collect();
+
+        UniformBakedLookupTable::new(min_x, step, ys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Knot, KnotInterpolation};
+    use bevy_math::Vec2;
+
+    #[test]
+    fn baked_table_matches_curve() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(-1.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(0.5, 2.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(3.0, 1.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let table = curve.bake_uniform(401);
+        assert_eq!(table.x0, -1.0);
+        assert_eq!(table.step, 0.01);
+        for i in 0..=400 {
+            let x = -1.0 + i as f32 * 0.01;
+            assert!((table.lookup(x) - curve.lookup(x)).abs() < 1e-3);
+        }
+        assert_eq!(table.lookup(-5.0), table.ys[0]);
+        assert_eq!(table.lookup(5.0), table.ys[400]);
+    }
+
+    #[test]
+    fn table_interpolates_between_samples() {
+        let table = UniformBakedLookupTable::new(1.0, 0.5, vec![0.0, 1.0, 3.0]);
+        assert_eq!(table.lookup(1.25), 0.5);
+        assert_eq!(table.lookup(1.5), 1.0);
+        assert_eq!(table.lookup(1.75), 2.0);
+        assert_eq!(table.lookup(2.0), 3.0);
+    }
+
+    #[test]
+    fn single_knot_bake_uniform() {
+        assert_eq!(LookupCurve::default().bake_uniform(4).lookup(0.5), 0.0);
+
+        let curve = LookupCurve::with_knots(vec![Knot {
+            position: Vec2::new(1.0, 2.0),
+            ..Default::default()
+        }])
+        .unwrap();
+        let table = curve.bake_uniform(4);
+        for x in [0.0, 1.0, 2.0] {
+            assert_eq!(table.lookup(x), 2.0);
+        }
+    }
+}
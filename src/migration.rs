@@ -0,0 +1,121 @@
+use crate::{LookupCurve, LookupCurveLoadError};
+
+impl LookupCurve {
+    /// Upgrades a [LookupCurve] deserialized from format `version` to the current format, see [LookupCurve::FORMAT_VERSION].
+    ///
+    /// Migrations are applied in sequence, one version at a time, on the typed curve.
+    /// Fields added or renamed in newer versions are handled by serde defaults and aliases when deserializing,
+    /// so migration steps only need to convert values whose meaning has changed.
+    pub fn migrate(version: u32, mut curve: LookupCurve) -> LookupCurve {
+        if version < 1 {
+            // 0 -> 1: Fields added before versioning are all covered by serde defaults, and no values changed meaning
+        }
+
+        curve.format_version = Self::FORMAT_VERSION;
+        curve
+    }
+
+    /// Deserializes a [LookupCurve] from RON, migrating it from older versions of the format if needed
+    pub(crate) fn from_ron_bytes(bytes: &[u8]) -> Result<Self, LookupCurveLoadError> {
+        let curve = ron::de::from_bytes::<LookupCurve>(bytes)?;
+        Ok(match curve.format_version {
+            version if version < Self::FORMAT_VERSION => Self::migrate(version, curve),
+            _ => curve,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KnotInterpolation;
+    use bevy_math::Vec2;
+
+    #[test]
+    fn loads_unversioned_curve() {
+        let v0 = r#"(
+  knots: [
+    (
+      position: (0.0, 0.0),
+      interpolation: Cubic,
+      left_tangent: (
+        slope: 0.0,
+        mode: Aligned,
+      ),
+      right_tangent: (
+        slope: 1.0,
+        mode: Free,
+      ),
+    ),
+    (
+      position: (1.0, 1.0),
+      interpolation: Linear,
+      left_tangent: (
+        slope: 0.0,
+        mode: Aligned,
+      ),
+      right_tangent: (
+        slope: 0.0,
+        mode: Aligned,
+      ),
+    ),
+  ],
+)"#;
+
+        let curve = LookupCurve::from_ron_bytes(v0.as_bytes()).unwrap();
+        assert_eq!(curve.format_version, LookupCurve::FORMAT_VERSION);
+        assert_eq!(curve.knots().len(), 2);
+        assert_eq!(curve.knots()[0].position, Vec2::ZERO);
+        assert!(matches!(
+            curve.knots()[0].interpolation,
+            KnotInterpolation::Cubic
+        ));
+        assert_eq!(curve.knots()[0].right_tangent.slope, 1.0);
+        assert!(matches!(
+            curve.knots()[1].interpolation,
+            KnotInterpolation::Linear
+        ));
+        assert_eq!(curve.tension, 0.5);
+        assert_eq!(curve.output_clamp, None);
+
+        let saved = curve.to_ron_string().unwrap();
+        assert!(saved.contains("format_version: 1"));
+        assert!(!saved.contains("anchor"));
+    }
+
+    #[test]
+    fn migration_keeps_enum_variants() {
+        let v0 = r#"(
+  knots: [
+    (
+      position: (0.0, 0.0),
+      interpolation: HermiteRaw(tangent_in: (1.0, 0.0), tangent_out: (1.0, 2.0)),
+      left_tangent: (slope: 0.0, mode: Free),
+      right_tangent: (slope: 2.0, mode: Free, weight: Some(0.3)),
+    ),
+    (
+      position: (1.0, 1.0),
+      interpolation: Constant,
+      left_tangent: (slope: 0.0, mode: Aligned),
+      right_tangent: (slope: 0.0, mode: Aligned),
+    ),
+  ],
+)"#;
+
+        let curve = LookupCurve::from_ron_bytes(v0.as_bytes()).unwrap();
+        assert_eq!(curve.format_version(), LookupCurve::FORMAT_VERSION);
+        assert!(matches!(
+            curve.knots()[0].interpolation,
+            KnotInterpolation::HermiteRaw { tangent_out, .. } if tangent_out == Vec2::new(1.0, 2.0)
+        ));
+        assert!(matches!(
+            curve.knots()[0].left_tangent.mode,
+            crate::TangentMode::Free
+        ));
+        assert_eq!(curve.knots()[0].right_tangent.weight, Some(0.3));
+        assert!(matches!(
+            curve.knots()[1].interpolation,
+            KnotInterpolation::Constant
+        ));
+    }
+}
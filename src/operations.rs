@@ -0,0 +1,2180 @@
+use bevy_math::Vec2;
+
+use crate::{
+    tangents_from_bezier, CubicSegment, Knot, KnotInterpolation, LookupCache, LookupCurve, Tangent,
+    TangentMode,
+};
+
+/// Error returned by [LookupCurve::merge]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeError {
+    /// The left curve ends after the right curve starts
+    OverlappingDomains,
+}
+
+impl std::fmt::Display for MergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OverlappingDomains => write!(f, "The curves to merge have overlapping domains"),
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// Direction of a threshold crossing, see [LookupCurve::find_first_x_where_y_crosses]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum ThresholdDirection {
+    /// y increases past the threshold
+    Rising,
+    /// y decreases past the threshold
+    Falling,
+}
+
+/// A knot where the slope of the curve is discontinuous, see [LookupCurve::continuity_violations]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContinuityViolation {
+    pub knot_index: usize,
+    /// Slope of the segment ending at the knot
+    pub left_slope: f32,
+    /// Slope of the segment starting at the knot
+    pub right_slope: f32,
+    /// Absolute difference between the slopes
+    pub delta: f32,
+}
+
+impl LookupCurve {
+    /// Constructs a new curve from `knots`, keeping the name and settings of this curve
+    fn derive_with_knots(&self, knots: Vec<Knot>) -> LookupCurve {
+        LookupCurve {
+            max_iters: self.max_iters,
+            max_error: self.max_error,
+            cubic_solver: self.cubic_solver,
+            output_clamp: self.output_clamp,
+            tension: self.tension,
+            name: self.name.clone(),
+            events: self.events.clone(),
+            miniature_resolution: self.miniature_resolution,
+            ..LookupCurve::new_unchecked(knots)
+        }
+    }
+
+    /// Inserts a knot at `x` without changing the shape of the curve, and returns its index.
+    ///
+    /// If a knot already exists at `x`, its index is returned instead.
+    fn subdivide_at(&mut self, x: f32) -> usize {
+        // Inserting a knot changes the shape of neighbouring cardinal, B-spline and raw Hermite segments
+        self.bake_cardinal_segments();
+        self.bake_bezier_segments();
+
+        let i = self.knots.partition_point(|k| k.position.x < x);
+        if self.knots.get(i).is_some_and(|k| k.position.x == x) {
+            return i;
+        }
+
+        let y = self.lookup_unclamped(x, None);
+        if i == 0 || i == self.knots.len() {
+            // Outside of the knot range the curve is flat
+            if i > 0 {
+                self.knots[i - 1].interpolation = KnotInterpolation::Linear;
+            }
+            self.knots.insert(
+                i,
+                Knot {
+                    position: Vec2::new(x, y),
+                    ..Default::default()
+                },
+            );
+            return i;
+        }
+
+        let knot_a = self.knots[i - 1].clone();
+        let knot_b = self.knots[i].clone();
+        let knot = match knot_a.interpolation {
+            KnotInterpolation::Constant | KnotInterpolation::Linear => Knot {
+                position: Vec2::new(x, y),
+                interpolation: knot_a.interpolation,
+                ..Default::default()
+            },
+            KnotInterpolation::Cubic
+            | KnotInterpolation::Cardinal
+            | KnotInterpolation::BSpline
+            | KnotInterpolation::HermiteRaw { .. } => {
+                let bezier = knot_a.compute_bezier_to(&knot_b);
+                let weighted =
+                    knot_a.right_tangent.weight.is_some() || knot_b.left_tangent.weight.is_some();
+                let t = if weighted {
+                    CubicSegment::from_bezier_points(bezier).find_t_given_x_bisection(
+                        x,
+                        self.max_error,
+                        self.max_iters.max(24),
+                    )
+                } else {
+                    // Unweighted segments are linear in x
+                    (x - knot_a.position.x) / (knot_b.position.x - knot_a.position.x)
+                };
+
+                // De Casteljau subdivision
+                let [p0, p1, p2, p3] = bezier;
+                let (p01, p12, p23) = (p0.lerp(p1, t), p1.lerp(p2, t), p2.lerp(p3, t));
+                let (p012, p123) = (p01.lerp(p12, t), p12.lerp(p23, t));
+                let p = p012.lerp(p123, t);
+
+                let (a_right, left) = tangents_from_bezier([p0, p01, p012, p]);
+                let (right, b_left) = tangents_from_bezier([p, p123, p23, p3]);
+                let weight = |tangent: Tangent| tangent.weight.filter(|_| weighted);
+
+                self.knots[i - 1].right_tangent = Tangent {
+                    slope: a_right.slope,
+                    weight: weight(a_right),
+                    ..knot_a.right_tangent
+                };
+                self.knots[i].left_tangent = Tangent {
+                    slope: b_left.slope,
+                    weight: weight(b_left),
+                    ..knot_b.left_tangent
+                };
+
+                Knot {
+                    position: Vec2::new(x, p.y),
+                    interpolation: KnotInterpolation::Cubic,
+                    left_tangent: Tangent {
+                        mode: TangentMode::Aligned,
+                        weight: weight(left),
+                        ..left
+                    },
+                    right_tangent: Tangent {
+                        mode: TangentMode::Aligned,
+                        weight: weight(right),
+                        ..right
+                    },
+                    ..Default::default()
+                }
+            }
+        };
+
+        self.knots.insert(i, knot);
+        i
+    }
+
+    /// Splits the curve at `x` into two curves. The first curve contains all knots with `position.x <= x`, and the second all knots with `position.x >= x`.
+    ///
+    /// If there is no knot at `x`, one is inserted without changing the shape of the curve. The knot at `x` is included in both curves.
+    /// Events with `x` below the split position go to the first curve, and the rest to the second.
+    pub fn split_at(&self, x: f32) -> (LookupCurve, LookupCurve) {
+        let mut curve = self.clone();
+        let i = curve.subdivide_at(x);
+        let (left_events, right_events) = self.events.iter().cloned().partition(|e| e.x < x);
+        (
+            LookupCurve {
+                events: left_events,
+                ..self.derive_with_knots(curve.knots[..=i].to_vec())
+            },
+            LookupCurve {
+                events: right_events,
+                ..self.derive_with_knots(curve.knots[i..].to_vec())
+            },
+        )
+    }
+
+    /// Sets the tangent slopes of all knots using [LookupCurve::catmull_rom_slope]
+    pub(crate) fn apply_catmull_rom_tangents(&mut self) {
+        for i in 0..self.knots.len() {
+            let slope = self.catmull_rom_slope(i);
+            self.knots[i].left_tangent.slope = slope;
+            self.knots[i].right_tangent.slope = slope;
+        }
+    }
+
+    /// Concatenates two curves end-to-end. The name and settings are taken from `left`, and the events of both curves are kept.
+    ///
+    /// If the last knot of `left` and the first knot of `right` share the same x-position, they are merged into one knot using the values of `right`, except for the left tangent which is kept from `left`.
+    /// Returns [MergeError::OverlappingDomains] if `left` ends after `right` starts.
+    pub fn merge(left: &LookupCurve, right: &LookupCurve) -> Result<LookupCurve, MergeError> {
+        let events = [left.events.as_slice(), right.events.as_slice()].concat();
+        if left.knots.is_empty() || right.knots.is_empty() {
+            let knots = [left.knots(), right.knots()].concat();
+            return Ok(LookupCurve {
+                events,
+                ..left.derive_with_knots(knots)
+            });
+        }
+        if left.domain().1 > right.domain().0 {
+            return Err(MergeError::OverlappingDomains);
+        }
+
+        let mut knots = left.knots.clone();
+        let mut right_knots = right.knots.iter();
+        if left.domain().1 == right.domain().0 {
+            let shared = knots.last_mut().unwrap();
+            *shared = Knot {
+                left_tangent: shared.left_tangent,
+                ..right_knots.next().unwrap().clone()
+            };
+        }
+        knots.extend(right_knots.cloned());
+
+        Ok(LookupCurve {
+            events,
+            ..left.derive_with_knots(knots)
+        })
+    }
+
+    /// Returns a new curve with the same knots, where the y-values have been smoothed using a Gaussian kernel.
+    ///
+    /// `sigma` is the standard deviation of the kernel in curve space (x-axis). Knots further away than `3 * sigma` do not contribute.
+    /// If `sigma` is not positive, the curve is returned unchanged.
+    pub fn smooth_gaussian(&self, sigma: f32) -> LookupCurve {
+        let mut curve = self.clone();
+        if sigma <= 0.0 {
+            return curve;
+        }
+
+        let radius = 3.0 * sigma;
+        let denom = 2.0 * sigma * sigma;
+        for (i, knot) in curve.knots.iter_mut().enumerate() {
+            let x = self.knots[i].position.x;
+            let start = self.knots.partition_point(|k| k.position.x < x - radius);
+            let end = self.knots.partition_point(|k| k.position.x <= x + radius);
+
+            let (sum, weight_sum) =
+                self.knots[start..end]
+                    .iter()
+                    .fold((0.0, 0.0), |(sum, weight_sum), k| {
+                        let dx = k.position.x - x;
+                        let weight = (-dx * dx / denom).exp();
+                        (
+                            sum + weight * self.lookup(k.position.x),
+                            weight_sum + weight,
+                        )
+                    });
+
+            knot.position.y = sum / weight_sum;
+        }
+
+        curve
+    }
+
+    /// Returns a new curve stretched along the x-axis by `factor`, for example to change the speed of an animation.
+    ///
+    /// All knot and event x-positions are multiplied by `factor` and all tangent slopes are divided by it, so that `scaled.lookup(x * factor) == self.lookup(x)`.
+    /// A `factor` above 1.0 stretches the curve over a longer time, and below 1.0 compresses it. Use [LookupCurve::transform] to mirror the curve.
+    ///
+    /// # Panics
+    /// Panics if `factor` is not positive and finite.
+    pub fn scale_time(&self, factor: f32) -> LookupCurve {
+        assert!(
+            factor > 0.0 && factor.is_finite(),
+            "scale_time factor must be positive and finite"
+        );
+        self.transform(0.0, factor, 0.0, 1.0)
+    }
+
+    /// Returns a new curve with the affine transformation `x' = x_offset + x * x_scale`, `y' = y_offset + y * y_scale` applied,
+    /// so that `transformed.lookup(x_offset + x * x_scale) == y_offset + y_scale * self.lookup(x)`.
+    ///
+    /// Tangent slopes are multiplied by `y_scale / x_scale`, and the [LookupCurve::output_clamp] and [LookupCurve::events] are transformed along with the knots.
+    /// A negative `x_scale` mirrors the curve, which reverses the order of the knots and swaps their tangents.
+    /// Mirrored [KnotInterpolation::Constant] segments hold the value of their other end, as the step always happens at the right knot.
+    /// A mirrored [KnotInterpolation::HermiteRaw] segment ending at a knot with a different interpolation keeps the direction of its end tangent,
+    /// but not its length unless it spans the width of the segment.
+    ///
+    /// # Panics
+    /// Panics if `x_scale` is zero or not finite.
+    pub fn transform(
+        &self,
+        x_offset: f32,
+        x_scale: f32,
+        y_offset: f32,
+        y_scale: f32,
+    ) -> LookupCurve {
+        assert!(
+            x_scale != 0.0 && x_scale.is_finite(),
+            "transform x_scale must be non-zero and finite"
+        );
+        let slope_scale = y_scale / x_scale;
+        let mut knots: Vec<Knot> = self
+            .knots
+            .iter()
+            .map(|knot| {
+                let mut knot = knot.clone();
+                knot.position.x = x_offset + knot.position.x * x_scale;
+                knot.position.y = y_offset + knot.position.y * y_scale;
+                knot.left_tangent.slope *= slope_scale;
+                knot.right_tangent.slope *= slope_scale;
+                if let KnotInterpolation::HermiteRaw {
+                    tangent_in,
+                    tangent_out,
+                } = &mut knot.interpolation
+                {
+                    let scale = Vec2::new(x_scale, y_scale);
+                    *tangent_in *= scale;
+                    *tangent_out *= scale;
+                }
+                knot
+            })
+            .collect();
+
+        if x_scale < 0.0 {
+            let n = knots.len();
+            // Tangent vectors at each knot as read by HermiteRaw segments on either side, see [LookupCurve::segment_bezier]
+            let raw_tangents: Vec<(Vec2, Vec2)> = (0..n)
+                .map(|i| match knots[i].interpolation {
+                    KnotInterpolation::HermiteRaw {
+                        tangent_in,
+                        tangent_out,
+                    } => (tangent_in, tangent_out),
+                    _ => {
+                        let in_width = if i > 0 {
+                            knots[i].position.x - knots[i - 1].position.x
+                        } else {
+                            0.0
+                        };
+                        let out_width = if i + 1 < n {
+                            knots[i + 1].position.x - knots[i].position.x
+                        } else {
+                            0.0
+                        };
+                        (
+                            Vec2::new(in_width, in_width * knots[i].left_tangent.slope),
+                            Vec2::new(out_width, out_width * knots[i].right_tangent.slope),
+                        )
+                    }
+                })
+                .collect();
+            let interpolations: Vec<KnotInterpolation> =
+                knots.iter().map(|knot| knot.interpolation).collect();
+
+            knots.reverse();
+            for (k, knot) in knots.iter_mut().enumerate() {
+                let i = n - 1 - k;
+                std::mem::swap(&mut knot.left_tangent, &mut knot.right_tangent);
+                // The interpolation of each segment moves from its left knot to its new left knot,
+                // while raw tangents stay with the knot they belong to
+                if i > 0 {
+                    knot.interpolation = interpolations[i - 1];
+                }
+                let (tangent_in, tangent_out) = raw_tangents[i];
+                if let KnotInterpolation::HermiteRaw {
+                    tangent_in: new_in,
+                    tangent_out: new_out,
+                } = &mut knot.interpolation
+                {
+                    (*new_in, *new_out) = (-tangent_out, -tangent_in);
+                } else if i + 1 < n
+                    && matches!(interpolations[i], KnotInterpolation::HermiteRaw { .. })
+                    && tangent_out.x != 0.0
+                {
+                    // The mirrored HermiteRaw segment ending here reads the left tangent slope
+                    knot.left_tangent.slope = tangent_out.y / tangent_out.x;
+                }
+            }
+        }
+
+        let mut transformed = self.derive_with_knots(knots);
+        transformed.output_clamp = self.output_clamp.map(|(min, max)| {
+            let (min, max) = (y_offset + min * y_scale, y_offset + max * y_scale);
+            (min.min(max), min.max(max))
+        });
+        for event in &mut transformed.events {
+            event.x = x_offset + event.x * x_scale;
+        }
+        transformed.events.sort_by(|a, b| a.x.total_cmp(&b.x));
+        transformed
+    }
+
+    /// Returns the knots where the curve is not C1 continuous, i.e. where the slopes of the segments on either side differ by more than `epsilon`.
+    ///
+    /// Unlike [TangentMode::Aligned], which keeps tangents aligned while editing, this validates the stored curve.
+    /// The slopes are those of the evaluated segments, so e.g. a [KnotInterpolation::Linear] segment uses the slope between its knots
+    /// rather than the stored tangent. The first and last knots are never reported.
+    pub fn continuity_violations(&self, epsilon: f32) -> Vec<ContinuityViolation> {
+        (1..self.knots.len().saturating_sub(1))
+            .filter_map(|i| {
+                let x = self.knots[i].position.x;
+                let left_slope = match self.knots[i - 1].interpolation {
+                    KnotInterpolation::Cubic => self.knots[i].left_tangent.slope,
+                    _ => self.interpolate_with_derivative(i - 1, x).1,
+                };
+                let right_slope = match self.knots[i].interpolation {
+                    KnotInterpolation::Cubic => self.knots[i].right_tangent.slope,
+                    _ => self.interpolate_with_derivative(i, x).1,
+                };
+                let delta = (left_slope - right_slope).abs();
+                (delta > epsilon).then_some(ContinuityViolation {
+                    knot_index: i,
+                    left_slope,
+                    right_slope,
+                    delta,
+                })
+            })
+            .collect()
+    }
+
+    /// Translates all knots, and the [LookupCurve::events], by `delta` along the x-axis.
+    ///
+    /// Tangent slopes are invariant under translation and left unchanged.
+    pub fn shift_x(&mut self, delta: f32) {
+        for knot in &mut self.knots {
+            knot.position.x += delta;
+        }
+        for event in &mut self.events {
+            event.x += delta;
+        }
+    }
+
+    /// Translates all knots, and the [LookupCurve::output_clamp], by `delta` along the y-axis.
+    ///
+    /// Tangent slopes are invariant under translation and left unchanged.
+    pub fn shift_y(&mut self, delta: f32) {
+        for knot in &mut self.knots {
+            knot.position.y += delta;
+        }
+        if let Some((min, max)) = &mut self.output_clamp {
+            *min += delta;
+            *max += delta;
+        }
+    }
+
+    /// Shifts the curve along the x-axis so that its [domain](LookupCurve::domain) is centered at `x = 0`.
+    pub fn center_x(&mut self) {
+        let (min_x, max_x) = self.domain();
+        self.shift_x(-(min_x + max_x) * 0.5);
+    }
+
+    /// Estimates the arc length of the segment starting at knot `segment_index`.
+    ///
+    /// Linear and constant segments are measured exactly. Cubic segments are split into `resolution` intervals, each integrated with 5-point Gauss-Legendre quadrature.
+    ///
+    /// # Panics
+    /// Panics if `segment_index` does not refer to a segment, i.e. is not less than the number of knots minus one.
+    pub fn segment_length(&self, segment_index: usize, resolution: usize) -> f32 {
+        const NODES: [(f32, f32); 5] = [
+            (0.0, 0.568_888_9),
+            (-0.538_469_3, 0.478_628_67),
+            (0.538_469_3, 0.478_628_67),
+            (-0.906_179_8, 0.236_926_88),
+            (0.906_179_8, 0.236_926_88),
+        ];
+
+        let (knot_a, knot_b) = (&self.knots[segment_index], &self.knots[segment_index + 1]);
+        let delta = knot_b.position - knot_a.position;
+        match knot_a.interpolation {
+            KnotInterpolation::Constant => delta.x.abs() + delta.y.abs(),
+            KnotInterpolation::Linear => delta.length(),
+            KnotInterpolation::Cubic
+            | KnotInterpolation::Cardinal
+            | KnotInterpolation::BSpline
+            | KnotInterpolation::HermiteRaw { .. } => {
+                let segment = CubicSegment::from_bezier_points(self.segment_bezier(segment_index));
+                let intervals = resolution.max(1);
+                let half_width = 0.5 / intervals as f32;
+                (0..intervals)
+                    .map(|i| {
+                        let center = (i as f32 + 0.5) / intervals as f32;
+                        NODES
+                            .iter()
+                            .map(|(node, weight)| {
+                                weight * segment.velocity(center + node * half_width).length()
+                            })
+                            .sum::<f32>()
+                            * half_width
+                    })
+                    .sum()
+            }
+        }
+    }
+
+    /// Estimates the total arc length of the curve within its domain, by summing [LookupCurve::segment_length] over all segments
+    ///
+    /// Returns `0.0` for curves with fewer than two knots.
+    pub fn arc_length(&self, resolution: usize) -> f32 {
+        (0..self.knots.len().saturating_sub(1))
+            .map(|i| self.segment_length(i, resolution))
+            .fold(0.0, |sum, length| sum + length)
+    }
+
+    /// Returns the indices of the cubic segments (by left knot) that overshoot beyond the y-values of both of their knots.
+    ///
+    /// Finds the critical points of each [KnotInterpolation::Cubic] and [KnotInterpolation::Cardinal] segment analytically, and checks whether any of them lies outside of the y-range of the segment knots.
+    /// Useful as a diagnostic to find segments where the tangents should be adjusted.
+    pub fn overshoot_segments(&self) -> Vec<usize> {
+        const TOLERANCE: f32 = 1e-5;
+
+        (0..self.knots.len().saturating_sub(1))
+            .filter(|i| {
+                matches!(
+                    self.knots[*i].interpolation,
+                    KnotInterpolation::Cubic | KnotInterpolation::Cardinal
+                )
+            })
+            .filter(|i| {
+                let (y_a, y_b) = (self.knots[*i].position.y, self.knots[*i + 1].position.y);
+                let (min, max) = (y_a.min(y_b) - TOLERANCE, y_a.max(y_b) + TOLERANCE);
+
+                let segment = CubicSegment::from_bezier_points(self.segment_bezier(*i));
+                let [_, b, c, d] = segment.coeff.map(|v| v.y);
+
+                // dy/dt = b + 2ct + 3dt²
+                let roots = if d.abs() < f32::EPSILON {
+                    if c.abs() < f32::EPSILON {
+                        vec![]
+                    } else {
+                        vec![-b / (2.0 * c)]
+                    }
+                } else {
+                    let discriminant = 4.0 * c * c - 12.0 * d * b;
+                    if discriminant < 0.0 {
+                        vec![]
+                    } else {
+                        let sqrt = discriminant.sqrt();
+                        vec![(-2.0 * c + sqrt) / (6.0 * d), (-2.0 * c - sqrt) / (6.0 * d)]
+                    }
+                };
+
+                roots
+                    .into_iter()
+                    .filter(|t| *t > 0.0 && *t < 1.0)
+                    .map(|t| segment.position(t).y)
+                    .any(|y| y < min || y > max)
+            })
+            .collect()
+    }
+
+    /// Samples both curves at `resolution` uniformly spaced x-values over the shared domain. Returns `None` if the domains do not overlap.
+    fn sample_shared_domain(
+        &self,
+        other: &LookupCurve,
+        resolution: usize,
+    ) -> Option<Vec<(f32, f32)>> {
+        let (self_min, self_max) = self.domain();
+        let (other_min, other_max) = other.domain();
+        let (min_x, max_x) = (self_min.max(other_min), self_max.min(other_max));
+        if min_x > max_x {
+            return None;
+        }
+
+        let divisor = resolution.saturating_sub(1).max(1) as f32;
+        let (mut self_cache, mut other_cache) = (LookupCache::new(), LookupCache::new());
+        Some(
+            (0..resolution)
+                .map(|i| {
+                    let x = min_x + (max_x - min_x) * i as f32 / divisor;
+                    (
+                        self.lookup_cached(x, &mut self_cache),
+                        other.lookup_cached(x, &mut other_cache),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the largest absolute difference in y between this curve and `other`, sampled at `resolution` points over the shared domain.
+    ///
+    /// The shared domain is the overlap of the [LookupCurve::domain] of both curves. Returns `f32::INFINITY` if the domains do not overlap.
+    pub fn max_deviation_from(&self, other: &LookupCurve, resolution: usize) -> f32 {
+        match self.sample_shared_domain(other, resolution) {
+            Some(samples) => samples
+                .iter()
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0, f32::max),
+            None => f32::INFINITY,
+        }
+    }
+
+    /// Returns the mean squared difference in y between this curve and `other`, sampled at `resolution` points over the shared domain.
+    ///
+    /// The shared domain is the overlap of the [LookupCurve::domain] of both curves. Returns `f32::INFINITY` if the domains do not overlap.
+    pub fn mean_squared_error_from(&self, other: &LookupCurve, resolution: usize) -> f32 {
+        match self.sample_shared_domain(other, resolution) {
+            Some(samples) if !samples.is_empty() => {
+                samples.iter().map(|(a, b)| (a - b).powi(2)).sum::<f32>() / samples.len() as f32
+            }
+            Some(_) => 0.0,
+            None => f32::INFINITY,
+        }
+    }
+
+    /// Returns true if both curves have the same number of knots, with positions, tangent slopes and tangent weights within `tolerance` of each other.
+    ///
+    /// Useful in tests of operations that go through floating-point arithmetic, see [assert_curves_approx_eq](crate::assert_curves_approx_eq).
+    /// Other properties of the knots and the curve, like interpolation and name, are not compared.
+    pub fn approx_eq(&self, other: &LookupCurve, tolerance: f32) -> bool {
+        let weight_eq = |a: Option<f32>, b: Option<f32>| match (a, b) {
+            (Some(a), Some(b)) => (a - b).abs() <= tolerance,
+            (None, None) => true,
+            _ => false,
+        };
+        let tangent_eq = |a: &Tangent, b: &Tangent| {
+            (a.slope - b.slope).abs() <= tolerance && weight_eq(a.weight, b.weight)
+        };
+
+        self.knots.len() == other.knots.len()
+            && self.knots.iter().zip(&other.knots).all(|(a, b)| {
+                a.position.abs_diff_eq(b.position, tolerance)
+                    && tangent_eq(&a.left_tangent, &b.left_tangent)
+                    && tangent_eq(&a.right_tangent, &b.right_tangent)
+            })
+    }
+
+    /// Returns a new curve with `count` knots at uniformly spaced x-values over the domain of this curve.
+    ///
+    /// The y-values are sampled using [LookupCurve::lookup], and the knots use [KnotInterpolation::Cubic] with tangents from [LookupCurve::catmull_rom_slope].
+    /// If the domain has zero length, e.g. for a single knot, at most one knot is returned to avoid knots sharing the same x-position.
+    pub fn resample_uniform(&self, count: usize) -> LookupCurve {
+        let (Some(first), Some(last)) = (self.knots.first(), self.knots.last()) else {
+            return self.derive_with_knots(vec![]);
+        };
+        let min_x = first.position.x;
+        let length = last.position.x - min_x;
+        let count = if length > 0.0 { count } else { count.min(1) };
+        let divisor = count.saturating_sub(1).max(1) as f32;
+
+        let mut cache = LookupCache::new();
+        let knots = (0..count)
+            .map(|i| {
+                let x = min_x + length * i as f32 / divisor;
+                Knot {
+                    position: Vec2::new(x, self.lookup_cached(x, &mut cache)),
+                    interpolation: KnotInterpolation::Cubic,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        let mut curve = self.derive_with_knots(knots);
+        curve.apply_catmull_rom_tangents();
+        curve
+    }
+
+    /// Returns a new curve with [KnotInterpolation::Linear] knots exactly at the x-values in `xs`, with y-values sampled using [LookupCurve::lookup].
+    ///
+    /// Useful for resampling at e.g. the frame times of an animation clip or at measurement points from external data.
+    /// `xs` does not need to be sorted. NaN values, and values closer than [LookupCurve::KNOT_X_EPSILON] to a smaller value, are skipped.
+    pub fn resample_at(&self, xs: &[f32]) -> LookupCurve {
+        let mut xs: Vec<f32> = xs.iter().copied().filter(|x| !x.is_nan()).collect();
+        xs.sort_by(f32::total_cmp);
+
+        let mut cache = LookupCache::new();
+        let mut knots: Vec<Knot> = Vec::with_capacity(xs.len());
+        for x in xs {
+            if knots
+                .last()
+                .is_some_and(|last| (x - last.position.x).abs() < Self::KNOT_X_EPSILON)
+            {
+                continue;
+            }
+            knots.push(Knot {
+                position: Vec2::new(x, self.lookup_cached(x, &mut cache)),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            });
+        }
+
+        self.derive_with_knots(knots)
+    }
+
+    /// Returns a new curve with each y-value raised to the power of `gamma`, as in color grading and exposure adjustments.
+    ///
+    /// The curve is resampled with `resolution` knots like [LookupCurve::resample_uniform], as the result can not be represented exactly.
+    /// The y-values are assumed to be in `[0, 1]` for this to make perceptual sense, negative values are clamped to zero.
+    pub fn apply_gamma(&self, gamma: f32, resolution: usize) -> LookupCurve {
+        let gamma_correct = |y: f32| y.max(0.0).powf(gamma);
+
+        let mut curve = self.resample_uniform(resolution);
+        for knot in &mut curve.knots {
+            knot.position.y = gamma_correct(knot.position.y);
+        }
+        curve.apply_catmull_rom_tangents();
+        curve.output_clamp = self
+            .output_clamp
+            .map(|(min, max)| (gamma_correct(min), gamma_correct(max)));
+        curve
+    }
+
+    /// Returns a new curve with the contrast of the y-values adjusted around `0.5`, using `y' = (y - 0.5) * contrast + 0.5`.
+    ///
+    /// A `contrast` above 1.0 increases the contrast, and below 1.0 decreases it. Unlike [LookupCurve::apply_gamma], the result is exact, see [LookupCurve::transform].
+    pub fn apply_contrast(&self, contrast: f32) -> LookupCurve {
+        self.transform(0.0, 1.0, 0.5 - 0.5 * contrast, contrast)
+    }
+
+    /// Returns a new curve with the output inverted, using `y' = 1.0 - y`, e.g. to turn a fade-in weight curve into a fade-out weight curve.
+    ///
+    /// All tangent slopes are negated. The result is exact, see [LookupCurve::transform].
+    pub fn invert_output(&self) -> LookupCurve {
+        self.transform(0.0, 1.0, 1.0, -1.0)
+    }
+
+    /// Returns a new curve without the knots that lie within `tolerance` of the simplified curve, using the Ramer-Douglas-Peucker algorithm.
+    ///
+    /// Distances are measured along the y-axis, between each knot and the line connecting the kept knots around it.
+    /// The first and last knots are always kept, and kept knots are left unchanged.
+    pub fn simplify(&self, tolerance: f32) -> LookupCurve {
+        let keep = self.douglas_peucker(tolerance);
+        let knots = self
+            .knots
+            .iter()
+            .zip(keep)
+            .filter(|(_, keep)| *keep)
+            .map(|(knot, _)| knot.clone())
+            .collect();
+        self.derive_with_knots(knots)
+    }
+
+    /// Returns a new curve with `target_n` knots, simplified using [LookupCurve::simplify].
+    ///
+    /// Binary searches for the tolerance yielding `target_n` knots. If no tolerance yields exactly `target_n` knots,
+    /// the nearest feasible count is used. At least two knots are kept, and the curve is returned unchanged if it has at most `target_n` knots.
+    pub fn simplify_to_n_knots(&self, target_n: usize) -> LookupCurve {
+        if self.knots.len() <= target_n.max(2) {
+            return self.clone();
+        }
+
+        let count = |tolerance| {
+            self.douglas_peucker(tolerance)
+                .into_iter()
+                .filter(|keep| *keep)
+                .count()
+        };
+
+        // No knot is further than the y-range of the knots from any line between two knots
+        let (min_y, max_y) = self
+            .knots
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(min, max), knot| {
+                (min.min(knot.position.y), max.max(knot.position.y))
+            });
+        let (mut low, mut high) = (0.0, max_y - min_y);
+        let mut best = (high, count(high));
+        for _ in 0..32 {
+            let tolerance = (low + high) * 0.5;
+            let n = count(tolerance);
+            let (distance, best_distance) = (n.abs_diff(target_n), best.1.abs_diff(target_n));
+            if distance < best_distance || (distance == best_distance && n > best.1) {
+                best = (tolerance, n);
+            }
+            match n.cmp(&target_n) {
+                std::cmp::Ordering::Equal => break,
+                std::cmp::Ordering::Greater => low = tolerance,
+                std::cmp::Ordering::Less => high = tolerance,
+            }
+        }
+
+        self.simplify(best.0)
+    }
+
+    /// Returns a new piecewise constant curve, where the y-values are rounded to the nearest multiple of `step`.
+    ///
+    /// Useful for parameters that must be integers or multiples of a step, such as damage values or item tiers.
+    /// A [KnotInterpolation::Constant] knot is placed at the start of the domain, and wherever the quantized value changes.
+    /// The positions of the changes are found by sampling each segment and refining with bisection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive and finite.
+    pub fn quantize(&self, step: f32) -> LookupCurve {
+        const SAMPLES_PER_SEGMENT: usize = 32;
+        const BISECTION_ITERS: usize = 24;
+
+        assert!(
+            step > 0.0 && step.is_finite(),
+            "quantize step must be positive and finite"
+        );
+        let quantized = |x: f32| (self.lookup(x) / step).round() * step;
+        // NaN lookups, e.g. from infinite knots, count as one value rather than changing at every sample
+        let same = |a: f32, b: f32| a == b || (a.is_nan() && b.is_nan());
+        let constant_knot = |x: f32, y: f32| Knot {
+            position: Vec2::new(x, y),
+            interpolation: KnotInterpolation::Constant,
+            ..Default::default()
+        };
+
+        let (Some(first), Some(last)) = (self.knots.first(), self.knots.last()) else {
+            return self.derive_with_knots(vec![]);
+        };
+        let xs = self
+            .knots
+            .windows(2)
+            .flat_map(|w| {
+                let (a, b) = (w[0].position.x, w[1].position.x);
+                (0..SAMPLES_PER_SEGMENT)
+                    .map(move |i| a + (b - a) * i as f32 / SAMPLES_PER_SEGMENT as f32)
+            })
+            .chain(std::iter::once(last.position.x));
+
+        let mut knots = vec![constant_knot(first.position.x, quantized(first.position.x))];
+        let mut prev_x = first.position.x;
+        for x in xs {
+            // There may be several changes between two samples on steep segments
+            while !same(quantized(x), knots.last().unwrap().position.y) {
+                let current_y = knots.last().unwrap().position.y;
+                let (mut low, mut high) = (prev_x, x);
+                for _ in 0..BISECTION_ITERS {
+                    let mid = (low + high) * 0.5;
+                    if same(quantized(mid), current_y) {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+
+                let knot = constant_knot(high, quantized(high));
+                let prev_knot = knots.last_mut().unwrap();
+                if high - prev_knot.position.x < Self::KNOT_X_EPSILON {
+                    *prev_knot = knot;
+                } else {
+                    knots.push(knot);
+                }
+                prev_x = high;
+            }
+            prev_x = x;
+        }
+
+        let mut curve = self.derive_with_knots(knots);
+        curve.output_clamp = None;
+        curve
+    }
+
+    /// Marks the knots kept by Ramer-Douglas-Peucker simplification with the given tolerance
+    fn douglas_peucker(&self, tolerance: f32) -> Vec<bool> {
+        let n = self.knots.len();
+        let mut keep = vec![false; n];
+        if n == 0 {
+            return keep;
+        }
+        keep[0] = true;
+        keep[n - 1] = true;
+
+        let mut ranges = vec![(0, n - 1)];
+        while let Some((a, b)) = ranges.pop() {
+            let (start, end) = (self.knots[a].position, self.knots[b].position);
+            let dx = end.x - start.x;
+
+            let mut furthest = None;
+            let mut max_distance = tolerance;
+            for (i, knot) in self.knots.iter().enumerate().take(b).skip(a + 1) {
+                let t = if dx > 0.0 {
+                    (knot.position.x - start.x) / dx
+                } else {
+                    0.0
+                };
+                let distance = (knot.position.y - (start.y + (end.y - start.y) * t)).abs();
+                if distance > max_distance {
+                    max_distance = distance;
+                    furthest = Some(i);
+                }
+            }
+
+            if let Some(i) = furthest {
+                keep[i] = true;
+                ranges.push((a, i));
+                ranges.push((i, b));
+            }
+        }
+
+        keep
+    }
+
+    /// Samples `resolution + 1` uniformly spaced points over the domain of the curve, or a single point if the domain has zero length.
+    /// Negative values are clamped to zero.
+    fn sample_non_negative(&self, resolution: usize) -> Vec<Vec2> {
+        let (Some(first), Some(last)) = (self.knots.first(), self.knots.last()) else {
+            return vec![];
+        };
+        let min_x = first.position.x;
+        let length = last.position.x - min_x;
+        if length <= 0.0 {
+            return vec![Vec2::new(min_x, first.position.y.max(0.0))];
+        }
+        let resolution = resolution.max(1);
+
+        let mut cache = LookupCache::new();
+        (0..=resolution)
+            .map(|i| {
+                let x = min_x + length * i as f32 / resolution as f32;
+                Vec2::new(x, self.lookup_cached(x, &mut cache).max(0.0))
+            })
+            .collect()
+    }
+
+    /// Returns the cumulative area under the sampled points using the trapezoidal rule
+    fn cumulative_area(points: &[Vec2]) -> Vec<f32> {
+        let mut area = 0.0;
+        std::iter::once(0.0)
+            .chain(points.windows(2).map(|w| {
+                area += (w[1].x - w[0].x) * (w[0].y + w[1].y) * 0.5;
+                area
+            }))
+            .collect()
+    }
+
+    /// Interprets the curve as a probability density function, and returns a linear approximation of it with a total area of 1.
+    ///
+    /// The curve is sampled at `resolution + 1` uniformly spaced points over its domain. Negative values are clamped to zero.
+    /// If the curve has no area, all y-values of the returned curve will be zero.
+    pub fn normalize_to_pdf(&self, resolution: usize) -> LookupCurve {
+        let points = self.sample_non_negative(resolution);
+        let area = Self::cumulative_area(&points)
+            .last()
+            .copied()
+            .unwrap_or(0.0);
+        let scale = if area > 0.0 { 1.0 / area } else { 0.0 };
+
+        let mut pdf = self.derive_with_knots(
+            points
+                .into_iter()
+                .map(|p| Knot {
+                    position: Vec2::new(p.x, p.y * scale),
+                    ..Default::default()
+                })
+                .collect(),
+        );
+        pdf.output_clamp = None;
+        pdf
+    }
+
+    /// Interprets the curve as a probability density function, and returns its cumulative distribution function as a new curve, ranging from 0 to 1.
+    ///
+    /// The curve is sampled at `resolution + 1` uniformly spaced points over its domain. Negative values are clamped to zero.
+    /// Use [LookupCurve::sample_inverse] on the returned curve to sample the distribution.
+    pub fn build_cdf(&self, resolution: usize) -> LookupCurve {
+        let points = self.sample_non_negative(resolution);
+        let cumulative = Self::cumulative_area(&points);
+        let area = cumulative.last().copied().unwrap_or(0.0);
+        let scale = if area > 0.0 { 1.0 / area } else { 0.0 };
+
+        let mut cdf = self.derive_with_knots(
+            points
+                .iter()
+                .zip(cumulative)
+                .map(|(p, c)| Knot {
+                    position: Vec2::new(p.x, c * scale),
+                    ..Default::default()
+                })
+                .collect(),
+        );
+        cdf.output_clamp = None;
+        cdf
+    }
+
+    /// Finds x for a given y on a monotonically increasing curve, such as a curve returned by [LookupCurve::build_cdf].
+    ///
+    /// Passing a uniformly distributed random value between 0 and 1 to a CDF samples the distribution.
+    /// Values outside of the range of the curve are clamped to the first or last knot.
+    pub fn sample_inverse(&self, uniform_random: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.knots.first(), self.knots.last()) else {
+            return 0.0;
+        };
+        if uniform_random <= first.position.y {
+            return first.position.x;
+        }
+        if uniform_random >= last.position.y {
+            return last.position.x;
+        }
+
+        let i = self
+            .knots
+            .partition_point(|k| k.position.y < uniform_random)
+            - 1;
+        let (knot_a, knot_b) = (&self.knots[i], &self.knots[i + 1]);
+
+        match knot_a.interpolation {
+            KnotInterpolation::Constant => knot_b.position.x,
+            KnotInterpolation::Linear => {
+                let s =
+                    (uniform_random - knot_a.position.y) / (knot_b.position.y - knot_a.position.y);
+                knot_a.position.x + s * (knot_b.position.x - knot_a.position.x)
+            }
+            KnotInterpolation::Cubic
+            | KnotInterpolation::Cardinal
+            | KnotInterpolation::BSpline
+            | KnotInterpolation::HermiteRaw { .. } => {
+                // Bisection, as the segment is only guaranteed to be monotonic
+                let (mut min, mut max) = (knot_a.position.x, knot_b.position.x);
+                let mut x = (min + max) * 0.5;
+                for _ in 0..self.max_iters {
+                    let error = self.lookup(x) - uniform_random;
+                    if error.abs() <= self.max_error {
+                        break;
+                    }
+                    if error < 0.0 {
+                        min = x;
+                    } else {
+                        max = x;
+                    }
+                    x = (min + max) * 0.5;
+                }
+                x
+            }
+        }
+    }
+
+    /// Finds the first x at or after `x_start` where y crosses `y_threshold` in the given `direction`, e.g. to trigger an effect during playback.
+    ///
+    /// A [ThresholdDirection::Rising] crossing is where y goes from below the threshold to at or above it, and a [ThresholdDirection::Falling] crossing
+    /// is where y goes from above the threshold to at or below it. If y is already past the threshold at `x_start`, it must first return for a crossing to occur.
+    ///
+    /// Each segment is sampled to find the crossing, which is then refined with bisection. Returns `None` if there is no crossing, as the curve is flat outside of its domain.
+    pub fn find_first_x_where_y_crosses(
+        &self,
+        y_threshold: f32,
+        x_start: f32,
+        direction: ThresholdDirection,
+    ) -> Option<f32> {
+        const SAMPLES_PER_SEGMENT: usize = 16;
+        const BISECTION_ITERS: usize = 32;
+
+        let is_past = |x: f32| {
+            let y = self.lookup(x);
+            match direction {
+                ThresholdDirection::Rising => y >= y_threshold,
+                ThresholdDirection::Falling => y <= y_threshold,
+            }
+        };
+
+        let xs = self
+            .knots
+            .windows(2)
+            .filter(|w| w[1].position.x > x_start)
+            .flat_map(|w| {
+                let (a, b) = (w[0].position.x.max(x_start), w[1].position.x);
+                (1..=SAMPLES_PER_SEGMENT)
+                    .map(move |i| a + (b - a) * i as f32 / SAMPLES_PER_SEGMENT as f32)
+            });
+
+        let (mut prev_x, mut prev_past) = (x_start, is_past(x_start));
+        for x in xs {
+            let past = is_past(x);
+            if past && !prev_past {
+                let (mut low, mut high) = (prev_x, x);
+                for _ in 0..BISECTION_ITERS {
+                    let mid = (low + high) * 0.5;
+                    if is_past(mid) {
+                        high = mid;
+                    } else {
+                        low = mid;
+                    }
+                }
+                return Some(high);
+            }
+            (prev_x, prev_past) = (x, past);
+        }
+
+        None
+    }
+}
+
+/// Asserts that two [LookupCurve]s are equal within a tolerance, see [LookupCurve::approx_eq]
+///
+/// Prints both curves on failure.
+#[macro_export]
+macro_rules! assert_curves_approx_eq {
+    ($a:expr, $b:expr, $tolerance:expr $(,)?) => {
+        match (&$a, &$b, $tolerance) {
+            (a, b, tolerance) => assert!(
+                $crate::LookupCurve::approx_eq(a, b, tolerance),
+                "curves are not equal within {}\n left: {:?}\nright: {:?}",
+                tolerance,
+                a,
+                b
+            ),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KnotEvent;
+
+    #[test]
+    fn approx_eq_within_tolerance() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.2)
+                .with_interpolation(KnotInterpolation::Cubic)
+                .with_slope(1.0),
+            Knot::at(1.0, 0.8).with_weights(Some(0.3), Some(0.3)),
+        ])
+        .unwrap();
+
+        let scaled = curve.scale_time(3.0).scale_time(1.0 / 3.0);
+        assert_curves_approx_eq!(curve, scaled, 1e-5);
+        assert_curves_approx_eq!(curve, curve.invert_output().invert_output(), 1e-5);
+
+        let mut moved = curve.clone();
+        moved.shift_y(1e-2);
+        assert!(curve.approx_eq(&moved, 1e-1));
+        assert!(!curve.approx_eq(&moved, 1e-3));
+
+        let mut reweighted = curve.clone();
+        reweighted.modify_knot_inplace(1, |knot| knot.left_tangent.weight = None);
+        assert!(!curve.approx_eq(&reweighted, 1.0));
+        assert!(!curve.approx_eq(&curve.split_at(0.5).0, 1.0));
+    }
+
+    #[test]
+    fn smooth_gaussian_reduces_spike() {
+        let curve = LookupCurve::with_knots(
+            (0..11)
+                .map(|i| Knot {
+                    position: Vec2::new(i as f32 * 0.1, if i == 5 { 1.0 } else { 0.0 }),
+                    ..Default::default()
+                })
+                .collect(),
+        )
+        .unwrap();
+
+        let smoothed = curve.smooth_gaussian(0.1);
+        let spike = smoothed.knots()[5].position.y;
+        assert!(spike < 1.0);
+        assert!(spike > 0.0);
+        assert!(smoothed.knots()[4].position.y > 0.0);
+        assert_eq!(smoothed.knots()[0].position.y, 0.0);
+    }
+
+    #[test]
+    fn resample_uniform_reproduces_curve() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(0.2, 1.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.5),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let resampled = curve.resample_uniform(101);
+        assert_eq!(resampled.knots().len(), 101);
+        assert_eq!(resampled.knots()[0].position.x, 0.0);
+        assert_eq!(resampled.knots()[100].position.x, 1.0);
+        for i in 0..=100 {
+            let x = i as f32 / 100.0;
+            assert!((resampled.lookup(x) - curve.lookup(x)).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn resample_at_places_knots_at_given_xs() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 1.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let xs = [0.25, -0.5, 0.1, 0.0, f32::NAN, 0.1, 1.5, 0.9];
+        let resampled = curve.resample_at(&xs);
+        let knots = resampled.knots();
+        assert_eq!(knots.len(), 6);
+        for knot in knots {
+            assert!(matches!(knot.interpolation, KnotInterpolation::Linear));
+            assert_eq!(knot.position.y, curve.lookup(knot.position.x));
+        }
+        assert_eq!(knots[2].position.x, 0.1);
+        assert_eq!(knots[3].position.x, 0.25);
+    }
+
+    #[test]
+    fn simplify_to_n_knots_hits_target() {
+        let knots = |ys: &[f32]| {
+            ys.iter()
+                .enumerate()
+                .map(|(i, y)| Knot {
+                    position: Vec2::new(i as f32 * 0.1, *y),
+                    interpolation: KnotInterpolation::Cubic,
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>()
+        };
+        let curves = [
+            LookupCurve::with_knots(knots(&[0.0, 1.0, -1.0, 0.5, 0.2, 0.9, 0.0, 3.0])).unwrap(),
+            LookupCurve::with_knots(knots(&[0.0, 0.0, 0.0, 0.0])).unwrap(),
+            LookupCurve::with_knots(knots(&[0.0, 1.0, 2.0, 3.0, 4.0])).unwrap(),
+            LookupCurve::with_knots(knots(&[1.0, 0.5])).unwrap(),
+        ];
+
+        for curve in &curves {
+            let simplified = curve.simplify_to_n_knots(2);
+            assert_eq!(simplified.knots().len(), 2);
+            assert_eq!(simplified.knots()[0].position, curve.knots()[0].position);
+            assert_eq!(
+                simplified.knots()[1].position,
+                curve.knots().last().unwrap().position
+            );
+        }
+
+        assert_eq!(curves[0].simplify_to_n_knots(6).knots().len(), 6);
+        // No tolerance yields 5 knots, the nearest feasible count is 6
+        assert_eq!(curves[0].simplify_to_n_knots(5).knots().len(), 6);
+        assert_eq!(curves[0].simplify_to_n_knots(20).knots().len(), 8);
+        // Knots on a straight line are all removed
+        assert_eq!(curves[2].simplify(0.0).knots().len(), 2);
+    }
+
+    #[test]
+    fn cdf_sampling_produces_correct_mean() {
+        // Triangular distribution with f(x) = 2x on [0, 1], which has a mean of 2/3
+        let pdf = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 1.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let normalized = pdf.normalize_to_pdf(100);
+        assert!((normalized.lookup(1.0) - 2.0).abs() < 1e-4);
+
+        let cdf = pdf.build_cdf(100);
+        assert_eq!(cdf.lookup(0.0), 0.0);
+        assert!((cdf.lookup(1.0) - 1.0).abs() < 1e-6);
+
+        const SAMPLES: usize = 10000;
+        let mean = (0..SAMPLES)
+            .map(|i| cdf.sample_inverse((i as f32 + 0.5) / SAMPLES as f32))
+            .sum::<f32>()
+            / SAMPLES as f32;
+        assert!((mean - 2.0 / 3.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn split_at_preserves_shape() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                right_tangent: Tangent {
+                    slope: 2.0,
+                    weight: Some(0.6),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(0.6, 1.0),
+                interpolation: KnotInterpolation::Cubic,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.4),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        for split_x in [0.3, 0.6, 0.8] {
+            let (left, right) = curve.split_at(split_x);
+            assert_eq!(left.knots().last().unwrap().position.x, split_x);
+            assert_eq!(right.knots().first().unwrap().position.x, split_x);
+            for i in 0..=100 {
+                let x = i as f32 / 100.0;
+                let half = if x <= split_x { &left } else { &right };
+                assert!((half.lookup(x) - curve.lookup(x)).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn scale_time_preserves_shape() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                right_tangent: Tangent {
+                    slope: 2.0,
+                    weight: Some(0.6),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(0.6, 1.0),
+                interpolation: KnotInterpolation::Cubic,
+                left_tangent: Tangent {
+                    slope: -1.0,
+                    ..Default::default()
+                },
+                right_tangent: Tangent {
+                    slope: 0.5,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.4),
+                ..Default::default()
+            },
+        ])
+        .unwrap()
+        .with_events(vec![crate::KnotEvent::new(0.6, "peak")]);
+
+        for factor in [0.25, 3.0] {
+            let scaled = curve.scale_time(factor);
+            assert_eq!(scaled.events[0].x, 0.6 * factor);
+            for i in 0..=100 {
+                let t = i as f32 / 100.0;
+                assert!((scaled.lookup(t * factor) - curve.lookup(t)).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "positive and finite")]
+    fn scale_time_rejects_zero_factor() {
+        LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)])
+            .unwrap()
+            .scale_time(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero and finite")]
+    fn transform_rejects_zero_x_scale() {
+        LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)])
+            .unwrap()
+            .transform(0.0, 0.0, 0.0, 1.0);
+    }
+
+    #[test]
+    fn continuity_violations_report_slope_breaks() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 1.0),
+                interpolation: KnotInterpolation::Cubic,
+                right_tangent: Tangent {
+                    slope: 1.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(2.0, 2.0),
+                interpolation: KnotInterpolation::Cubic,
+                left_tangent: Tangent {
+                    slope: 1.0,
+                    ..Default::default()
+                },
+                right_tangent: Tangent {
+                    slope: -1.0,
+                    mode: TangentMode::Free,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(3.0, 1.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        assert_eq!(
+            curve.continuity_violations(1e-4),
+            vec![ContinuityViolation {
+                knot_index: 2,
+                left_slope: 1.0,
+                right_slope: -1.0,
+                delta: 2.0,
+            }]
+        );
+        assert!(curve.continuity_violations(2.5).is_empty());
+    }
+
+    #[test]
+    fn gamma_and_contrast_adjust_values() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 1.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let gamma = curve.apply_gamma(2.2, 101);
+        let contrast = curve.apply_contrast(2.0);
+        for i in 0..=20 {
+            let x = i as f32 / 20.0;
+            let y = curve.lookup(x);
+            assert!((gamma.lookup(x) - y.powf(2.2)).abs() < 0.01);
+            assert!((contrast.lookup(x) - ((y - 0.5) * 2.0 + 0.5)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn shifted_curve_matches_original() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(1.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                right_tangent: Tangent {
+                    slope: 2.0,
+                    weight: Some(0.4),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(3.0, 1.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let mut shifted = curve.clone();
+        shifted.shift_x(-0.5);
+        shifted.shift_y(2.0);
+        for i in 0..=10 {
+            let x = 1.0 + i as f32 * 0.2;
+            assert!((shifted.lookup(x - 0.5) - (curve.lookup(x) + 2.0)).abs() < 1e-5);
+        }
+
+        shifted.center_x();
+        assert_eq!(shifted.domain(), (-1.0, 1.0));
+        assert_eq!(shifted.knots()[0].right_tangent.slope, 2.0);
+    }
+
+    #[test]
+    fn transform_mirrors_mixed_interpolations() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.0).with_interpolation(KnotInterpolation::Constant),
+            Knot::at(1.0, 1.0).with_interpolation(KnotInterpolation::Linear),
+            Knot::at(2.0, 0.0)
+                .with_interpolation(KnotInterpolation::Cubic)
+                .with_slope(1.0),
+            Knot::from_hermite_raw(
+                Vec2::new(3.0, 1.0),
+                Vec2::new(1.0, 2.0),
+                Vec2::new(1.0, -1.0),
+            ),
+            Knot::from_hermite_raw(
+                Vec2::new(4.0, 0.5),
+                Vec2::new(1.0, 0.5),
+                Vec2::new(1.0, 0.0),
+            ),
+            Knot::at(5.0, 0.0),
+        ])
+        .unwrap();
+
+        let (x_offset, x_scale, y_offset, y_scale) = (1.0, -2.0, 0.5, 3.0);
+        let mirrored = curve.transform(x_offset, x_scale, y_offset, y_scale);
+        for i in 0..=500 {
+            let x = i as f32 / 100.0;
+            // The mirrored constant segment holds the value of its other end
+            let y = if x > 0.0 && x <= 1.0 {
+                1.0
+            } else {
+                curve.lookup(x)
+            };
+            let actual = mirrored.lookup(x_offset + x * x_scale);
+            assert!(
+                (actual - (y_offset + y_scale * y)).abs() < 1e-3,
+                "x = {x}: {actual} != {}",
+                y_offset + y_scale * y
+            );
+        }
+    }
+
+    #[test]
+    fn transform_mirrors_curve() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                right_tangent: Tangent {
+                    slope: 2.0,
+                    weight: Some(0.7),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(0.6, 1.0),
+                interpolation: KnotInterpolation::Linear,
+                left_tangent: Tangent {
+                    slope: -1.0,
+                    weight: Some(0.2),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.4),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        for (x_offset, x_scale, y_offset, y_scale) in
+            [(1.0, 2.0, -1.0, 0.5), (2.0, -3.0, 0.5, -2.0)]
+        {
+            let transformed = curve.transform(x_offset, x_scale, y_offset, y_scale);
+            for i in 0..=100 {
+                let x = i as f32 / 100.0;
+                let expected = y_offset + y_scale * curve.lookup(x);
+                assert!((transformed.lookup(x_offset + x * x_scale) - expected).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn deviation_over_shared_domain() {
+        let line = |from: Vec2, to: Vec2| {
+            LookupCurve::with_knots(vec![
+                Knot {
+                    position: from,
+                    interpolation: KnotInterpolation::Linear,
+                    ..Default::default()
+                },
+                Knot {
+                    position: to,
+                    ..Default::default()
+                },
+            ])
+            .unwrap()
+        };
+        let a = line(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+        let b = line(Vec2::new(1.0, 1.0), Vec2::new(3.0, 3.0));
+        let c = line(Vec2::new(0.0, 1.0), Vec2::new(2.0, 1.0));
+
+        assert!(a.max_deviation_from(&b, 11) < 1e-6);
+        assert!(a.mean_squared_error_from(&b, 11) < 1e-6);
+
+        assert!((a.max_deviation_from(&c, 11) - 1.0).abs() < 1e-6);
+        assert!((a.mean_squared_error_from(&c, 3) - 2.0 / 3.0).abs() < 1e-6);
+
+        let d = line(Vec2::new(5.0, 0.0), Vec2::new(6.0, 0.0));
+        assert_eq!(a.max_deviation_from(&d, 11), f32::INFINITY);
+        assert_eq!(a.mean_squared_error_from(&d, 11), f32::INFINITY);
+    }
+
+    #[test]
+    fn overshoot_segments_finds_cubic_overshoot() {
+        let cubic = |x: f32, y: f32, slope: f32| Knot {
+            position: Vec2::new(x, y),
+            interpolation: KnotInterpolation::Cubic,
+            left_tangent: Tangent {
+                slope,
+                ..Default::default()
+            },
+            right_tangent: Tangent {
+                slope,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let curve = LookupCurve::with_knots(vec![
+            cubic(0.0, 0.0, 1.0),
+            cubic(1.0, 1.0, 8.0),
+            cubic(2.0, 1.5, 0.0),
+            cubic(3.0, 0.0, 0.0),
+        ])
+        .unwrap();
+
+        assert_eq!(curve.overshoot_segments(), vec![0, 1]);
+    }
+
+    #[test]
+    fn split_at_preserves_cardinal_shape() {
+        let curve = LookupCurve::with_knots(
+            [0.0, 0.8, 0.3, 1.0]
+                .iter()
+                .enumerate()
+                .map(|(i, y)| Knot {
+                    position: Vec2::new(i as f32 / 3.0, *y),
+                    interpolation: KnotInterpolation::Cardinal,
+                    ..Default::default()
+                })
+                .collect(),
+        )
+        .unwrap()
+        .with_tension(0.3);
+
+        let (left, right) = curve.split_at(0.5);
+        for i in 0..=100 {
+            let x = i as f32 / 100.0;
+            let half = if x <= 0.5 { &left } else { &right };
+            assert!((half.lookup(x) - curve.lookup(x)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn bspline_passes_through_run_endpoints_only() {
+        let curve = LookupCurve::with_knots(
+            [0.0, 1.0, 0.0, 1.0]
+                .iter()
+                .enumerate()
+                .map(|(i, y)| Knot {
+                    position: Vec2::new(i as f32, *y),
+                    interpolation: KnotInterpolation::BSpline,
+                    ..Default::default()
+                })
+                .collect(),
+        )
+        .unwrap();
+
+        assert!(curve.lookup(0.0).abs() < 1e-4);
+        assert!((curve.lookup(3.0) - 1.0).abs() < 1e-4);
+        assert!(curve.lookup(1.0) < 0.9);
+
+        let (left, right) = curve.split_at(1.5);
+        for i in 0..=90 {
+            let x = i as f32 / 30.0;
+            let half = if x <= 1.5 { &left } else { &right };
+            assert!((half.lookup(x) - curve.lookup(x)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn hermite_raw_matches_cubic() {
+        let knot = |x: f32, y: f32, slope: f32| Knot {
+            position: Vec2::new(x, y),
+            interpolation: KnotInterpolation::Cubic,
+            left_tangent: Tangent {
+                slope,
+                ..Default::default()
+            },
+            right_tangent: Tangent {
+                slope,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let cubic =
+            LookupCurve::with_knots(vec![knot(0.0, 0.0, 2.0), knot(2.0, 1.0, -0.5)]).unwrap();
+        let raw = LookupCurve::with_knots(vec![
+            cubic.knots()[0].to_hermite_raw(2.0, 2.0),
+            cubic.knots()[1].to_hermite_raw(2.0, 2.0),
+        ])
+        .unwrap();
+
+        for i in 0..=40 {
+            let x = i as f32 / 20.0;
+            assert!((raw.lookup(x) - cubic.lookup(x)).abs() < 1e-4);
+        }
+
+        let (left, right) = raw.split_at(0.7);
+        for i in 0..=40 {
+            let x = i as f32 / 20.0;
+            let half = if x <= 0.7 { &left } else { &right };
+            assert!((half.lookup(x) - raw.lookup(x)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn arc_length_of_straight_segments() {
+        let knot = |x: f32, y: f32, interpolation: KnotInterpolation| Knot {
+            position: Vec2::new(x, y),
+            interpolation,
+            left_tangent: Tangent {
+                slope: 1.0,
+                ..Default::default()
+            },
+            right_tangent: Tangent {
+                slope: 1.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let curve = LookupCurve::with_knots(vec![
+            knot(0.0, 0.0, KnotInterpolation::Linear),
+            knot(3.0, 4.0, KnotInterpolation::Cubic),
+            knot(4.0, 5.0, KnotInterpolation::Constant),
+            knot(5.0, 3.0, KnotInterpolation::Constant),
+        ])
+        .unwrap();
+
+        assert!((curve.segment_length(0, 1) - 5.0).abs() < 1e-5);
+        assert!((curve.segment_length(1, 4) - 2.0_f32.sqrt()).abs() < 1e-5);
+        assert!((curve.segment_length(2, 1) - 3.0).abs() < 1e-5);
+        assert!((curve.arc_length(4) - (8.0 + 2.0_f32.sqrt())).abs() < 1e-4);
+    }
+
+    #[test]
+    fn merge_split_reproduces_curve() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(0.5, 1.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.2),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let (left, right) = curve.split_at(0.25);
+        let merged = LookupCurve::merge(&left, &right).unwrap();
+        assert_eq!(merged.knots().len(), 4);
+        for i in 0..=100 {
+            let x = i as f32 / 100.0;
+            assert!((merged.lookup(x) - curve.lookup(x)).abs() < 1e-4);
+        }
+
+        assert_eq!(
+            LookupCurve::merge(&right, &left).unwrap_err(),
+            MergeError::OverlappingDomains
+        );
+    }
+
+    #[test]
+    fn merge_split_keeps_events() {
+        let curve = LookupCurve::default().with_events(vec![
+            KnotEvent::new(0.1, "a"),
+            KnotEvent::new(0.5, "b"),
+            KnotEvent::new(0.9, "c"),
+        ]);
+
+        let (left, right) = curve.split_at(0.5);
+        assert_eq!(left.events, [KnotEvent::new(0.1, "a")]);
+        assert_eq!(
+            right.events,
+            [KnotEvent::new(0.5, "b"), KnotEvent::new(0.9, "c")]
+        );
+        assert_eq!(
+            LookupCurve::merge(&left, &right).unwrap().events,
+            curve.events
+        );
+    }
+
+    #[test]
+    fn quantize_linear_to_staircase() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 1.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let quantized = curve.quantize(0.25);
+        let knots = quantized.knots();
+        assert_eq!(knots.len(), 5);
+        for (i, (knot, x)) in knots
+            .iter()
+            .zip([0.0, 0.125, 0.375, 0.625, 0.875])
+            .enumerate()
+        {
+            assert!(matches!(knot.interpolation, KnotInterpolation::Constant));
+            assert!((knot.position.x - x).abs() < 1e-4);
+            assert_eq!(knot.position.y, i as f32 * 0.25);
+        }
+
+        assert_eq!(quantized.lookup(0.1), 0.0);
+        assert_eq!(quantized.lookup(0.3), 0.25);
+        assert_eq!(quantized.lookup(0.7), 0.75);
+        assert_eq!(quantized.lookup(1.0), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "positive and finite")]
+    fn quantize_rejects_zero_step() {
+        LookupCurve::with_knots(vec![Knot::at(0.0, 0.0), Knot::at(1.0, 1.0)])
+            .unwrap()
+            .quantize(0.0);
+    }
+
+    #[test]
+    fn quantize_terminates_on_nan_lookups() {
+        // Interpolating towards and away from infinity yields NaN
+        let curve = LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.0),
+            Knot::at(1.0, f32::INFINITY),
+            Knot::at(2.0, 0.0),
+        ])
+        .unwrap();
+        assert!(!curve.quantize(0.5).knots().is_empty());
+    }
+
+    #[test]
+    fn invert_output_twice_reproduces_curve() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.2),
+                interpolation: KnotInterpolation::Cubic,
+                right_tangent: Tangent {
+                    slope: 2.0,
+                    weight: Some(0.4),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(0.5, 0.9),
+                interpolation: KnotInterpolation::Linear,
+                left_tangent: Tangent {
+                    slope: -1.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.4),
+                ..Default::default()
+            },
+        ])
+        .unwrap()
+        .with_output_clamp(0.1, 0.8);
+
+        let inverted = curve.invert_output();
+        let (min, max) = inverted.output_clamp.unwrap();
+        assert!((min - 0.2).abs() < 1e-6 && (max - 0.9).abs() < 1e-6);
+        assert_eq!(inverted.knots()[0].right_tangent.slope, -2.0);
+        for x in [0.1, 0.3, 0.7] {
+            assert!((inverted.lookup(x) - (1.0 - curve.lookup(x))).abs() < 1e-5);
+        }
+
+        let restored = inverted.invert_output();
+        for (a, b) in restored.knots().iter().zip(curve.knots()) {
+            assert!(a.position.abs_diff_eq(b.position, 1e-6));
+            assert_eq!(a.left_tangent.slope, b.left_tangent.slope);
+            assert_eq!(a.right_tangent.slope, b.right_tangent.slope);
+        }
+        let (min, max) = restored.output_clamp.unwrap();
+        assert!((min - 0.1).abs() < 1e-6 && (max - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn threshold_crossings() {
+        use ThresholdDirection::*;
+        let approx = |x: Option<f32>, expected: f32| (x.unwrap() - expected).abs() < 1e-5;
+
+        let linear = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.5),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(2.0, 0.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+        assert!(approx(
+            linear.find_first_x_where_y_crosses(0.25, -1.0, Rising),
+            0.5
+        ));
+        assert!(approx(
+            linear.find_first_x_where_y_crosses(0.25, 0.0, Falling),
+            1.5
+        ));
+        // Exactly at the value of a knot
+        assert!(approx(
+            linear.find_first_x_where_y_crosses(0.5, 0.0, Rising),
+            1.0
+        ));
+        assert_eq!(
+            linear.find_first_x_where_y_crosses(0.5001, 0.0, Rising),
+            None
+        );
+        assert_eq!(linear.find_first_x_where_y_crosses(0.25, 0.6, Rising), None);
+
+        let step = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Constant,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 1.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+        assert_eq!(
+            step.find_first_x_where_y_crosses(0.5, 0.0, Rising),
+            Some(1.0)
+        );
+        assert_eq!(
+            step.find_first_x_where_y_crosses(1.0, 0.0, Rising),
+            Some(1.0)
+        );
+        assert_eq!(step.find_first_x_where_y_crosses(0.5, 0.0, Falling), None);
+
+        // y = 4x(1 - x), peaking at 1.0 for x = 0.5
+        let bump = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                right_tangent: Tangent {
+                    slope: 4.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.0),
+                left_tangent: Tangent {
+                    slope: -4.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+        assert!(approx(
+            bump.find_first_x_where_y_crosses(0.75, 0.0, Rising),
+            0.25
+        ));
+        assert!(approx(
+            bump.find_first_x_where_y_crosses(0.75, 0.0, Falling),
+            0.75
+        ));
+        assert_eq!(bump.find_first_x_where_y_crosses(1.001, 0.0, Rising), None);
+    }
+
+    #[test]
+    fn bezier_path_covers_all_segments() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Constant,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 1.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(2.0, 4.0),
+                interpolation: KnotInterpolation::Cubic,
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(3.0, 0.0),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        assert_eq!(
+            curve.as_polyline(),
+            [
+                Vec2::new(0.0, 0.0),
+                Vec2::new(1.0, 1.0),
+                Vec2::new(2.0, 4.0),
+                Vec2::new(3.0, 0.0)
+            ]
+        );
+
+        let path = curve.as_bezier_path();
+        assert_eq!(path.len(), 4);
+        assert_eq!(path[0][0], Vec2::new(0.0, 0.0));
+        assert_eq!(path[0][3], Vec2::new(1.0, 0.0));
+        assert_eq!(path[1][3], Vec2::new(1.0, 1.0));
+        assert!(path[2][1].abs_diff_eq(Vec2::new(4.0 / 3.0, 2.0), 1e-6));
+        assert_eq!(path[3], curve.bezier_segments().next().unwrap());
+        for w in path.windows(2) {
+            assert_eq!(w[0][3], w[1][0]);
+        }
+    }
+
+    fn degenerate_curves() -> [LookupCurve; 3] {
+        let knot = |x, y| Knot {
+            position: Vec2::new(x, y),
+            interpolation: KnotInterpolation::Cubic,
+            ..Default::default()
+        };
+        [
+            LookupCurve::default(),
+            LookupCurve::with_knots(vec![knot(1.0, 2.0)]).unwrap(),
+            LookupCurve::with_knots(vec![knot(0.0, 0.0), knot(1.0, 1.0)]).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn single_knot_lookup() {
+        let [empty, single, pair] = degenerate_curves();
+        for x in [-1.0, 0.5, 1.0, 5.0] {
+            assert_eq!(empty.lookup(x), 0.0);
+            assert_eq!(single.lookup(x), 2.0);
+            assert_eq!(empty.lookup_with_derivative(x), (0.0, 0.0));
+            assert_eq!(single.lookup_with_derivative(x), (2.0, 0.0));
+        }
+        assert_eq!(pair.lookup(0.5), 0.5);
+        assert_eq!(pair.lookup_with_derivative(1.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn single_knot_arc_length() {
+        let [empty, single, pair] = degenerate_curves();
+        assert_eq!(empty.arc_length(4).to_bits(), 0.0_f32.to_bits());
+        assert_eq!(single.arc_length(4).to_bits(), 0.0_f32.to_bits());
+        assert!(pair.arc_length(4) > 2.0_f32.sqrt());
+    }
+
+    #[test]
+    fn single_knot_resample_uniform() {
+        let [empty, single, pair] = degenerate_curves();
+        assert!(empty.resample_uniform(4).knots().is_empty());
+        let resampled = single.resample_uniform(4);
+        assert_eq!(resampled.knots().len(), 1);
+        assert_eq!(resampled.knots()[0].position, Vec2::new(1.0, 2.0));
+        assert_eq!(single.apply_gamma(2.0, 4).knots().len(), 1);
+        assert_eq!(pair.resample_uniform(4).knots().len(), 4);
+    }
+
+    #[test]
+    fn single_knot_pdf_and_cdf() {
+        let [empty, single, pair] = degenerate_curves();
+        assert!(empty.normalize_to_pdf(4).knots().is_empty());
+        assert!(empty.build_cdf(4).knots().is_empty());
+        assert_eq!(empty.sample_inverse(0.5), 0.0);
+
+        // A single knot has no area
+        for curve in [single.normalize_to_pdf(4), single.build_cdf(4)] {
+            assert_eq!(curve.knots().len(), 1);
+            assert_eq!(curve.knots()[0].position, Vec2::new(1.0, 0.0));
+        }
+        assert_eq!(single.sample_inverse(0.5), 1.0);
+
+        assert_eq!(pair.build_cdf(4).knots().len(), 5);
+    }
+
+    #[test]
+    fn single_knot_operations() {
+        for curve in degenerate_curves() {
+            let n = curve.knots().len();
+            assert_eq!(curve.smooth_gaussian(0.5).knots().len(), n);
+            assert_eq!(curve.transform(1.0, -1.0, 0.0, 2.0).knots().len(), n);
+            assert_eq!(curve.simplify(0.1).knots().len(), n);
+            assert_eq!(curve.simplify_to_n_knots(1).knots().len(), n);
+            assert!(curve.continuity_violations(0.0).is_empty());
+            assert!(curve.overshoot_segments().is_empty());
+            assert_eq!(curve.max_deviation_from(&curve, 4), 0.0);
+            assert_eq!(curve.mean_squared_error_from(&curve, 4), 0.0);
+            assert_eq!(curve.bezier_segments().count(), n.saturating_sub(1));
+        }
+    }
+
+    #[test]
+    fn single_knot_split_and_merge() {
+        let [empty, single, pair] = degenerate_curves();
+
+        let (left, right) = empty.split_at(0.5);
+        assert_eq!(left.knots().len(), 1);
+        assert_eq!(right.knots().len(), 1);
+        assert!(LookupCurve::merge(&empty, &empty)
+            .unwrap()
+            .knots()
+            .is_empty());
+
+        let (left, right) = single.split_at(0.5);
+        assert_eq!(left.lookup(0.0), 2.0);
+        assert_eq!(right.lookup(2.0), 2.0);
+        assert_eq!(
+            LookupCurve::merge(&empty, &single).unwrap().lookup(0.0),
+            2.0
+        );
+        assert_eq!(
+            LookupCurve::merge(&single, &single).unwrap().lookup(0.0),
+            2.0
+        );
+
+        let (left, right) = pair.split_at(0.5);
+        assert_eq!(LookupCurve::merge(&left, &right).unwrap().knots().len(), 3);
+    }
+
+    #[test]
+    fn single_knot_queries() {
+        let [empty, single, pair] = degenerate_curves();
+        for curve in [&empty, &single] {
+            assert!(curve.next_knot(0).is_none());
+            assert_eq!(curve.events_between(0.0, 1.0).count(), 0);
+            assert_eq!(
+                curve.find_first_x_where_y_crosses(1.0, 0.0, ThresholdDirection::Rising),
+                None
+            );
+            assert!(curve.quantize(0.5).knots().len() <= 1);
+        }
+        assert_eq!(single.quantize(0.5).lookup(0.0), 2.0);
+        assert_eq!(pair.next_knot(0).unwrap().position, Vec2::ONE);
+        assert!(pair.next_knot(1).is_none());
+        assert_eq!(pair.segment_length(0, 4), pair.arc_length(4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_knot_has_no_segment() {
+        let [_, single, _] = degenerate_curves();
+        single.segment_length(0, 4);
+    }
+
+    #[test]
+    fn single_knot_export() {
+        for curve in degenerate_curves() {
+            assert!(curve.to_svg_path((0.0, 0.0, 1.0, 1.0)).starts_with("<path"));
+            assert!(curve.to_unreal_curve_csv(4).starts_with("---"));
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn single_knot_lookup_simd4() {
+        for curve in degenerate_curves() {
+            let xs = [-1.0, 0.0, 0.5, 2.0];
+            assert_eq!(curve.lookup_simd4(xs), xs.map(|x| curve.lookup(x)));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn single_knot_lookup_parallel() {
+        for curve in degenerate_curves() {
+            let xs = [-1.0, 0.0, 0.5, 2.0];
+            let mut out = [f32::NAN; 4];
+            curve.lookup_parallel(&xs, &mut out);
+            assert_eq!(out, xs.map(|x| curve.lookup(x)));
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use rayon::prelude::*;
+
+use crate::LookupCurve;
+
+/// Smallest number of x-values evaluated by each thread in [LookupCurve::lookup_parallel]
+const MIN_CHUNK_LEN: usize = 256;
+
+impl LookupCurve {
+    /// Find y for each x in `xs` using multiple threads, writing the results to `out`.
+    ///
+    /// `xs` is split into one chunk per thread, each evaluated like [LookupCurve::lookup_batch] with its own [LookupCache](crate::LookupCache).
+    /// Spreading the work has an overhead, so small batches are faster to evaluate on a single thread. See the `parallel` benchmark.
+    ///
+    /// # Panics
+    /// Panics if `xs` and `out` have different lengths.
+    pub fn lookup_parallel(&self, xs: &[f32], out: &mut [f32]) {
+        assert_eq!(xs.len(), out.len(), "xs and out must have the same length");
+
+        let chunk_len = xs
+            .len()
+            .div_ceil(rayon::current_num_threads())
+            .max(MIN_CHUNK_LEN);
+        xs.par_chunks(chunk_len)
+            .zip(out.par_chunks_mut(chunk_len))
+            .for_each(|(xs, out)| self.lookup_batch(xs, out));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Knot, KnotInterpolation};
+    use bevy_math::Vec2;
+
+    #[test]
+    fn parallel_matches_batch() {
+        let knots = (0..50)
+            .map(|i| Knot {
+                position: Vec2::new(i as f32, (i % 3) as f32),
+                interpolation: KnotInterpolation::Cubic,
+                ..Default::default()
+            })
+            .collect();
+        let curve = LookupCurve::with_knots(knots).unwrap();
+
+        let xs: Vec<f32> = (0..10000).map(|i| i as f32 * 0.005 - 1.0).collect();
+        let mut expected = vec![0.0; xs.len()];
+        let mut out = vec![0.0; xs.len()];
+        curve.lookup_batch(&xs, &mut expected);
+        curve.lookup_parallel(&xs, &mut out);
+        assert_eq!(out, expected);
+    }
+}
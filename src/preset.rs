@@ -0,0 +1,86 @@
+use bevy_math::Vec2;
+use std::f32::consts::PI;
+
+use crate::{Knot, KnotInterpolation, LookupCurve, Tangent};
+
+/// Predefined curve shapes, all ranging from (0, 0) to (1, 1)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum CurvePreset {
+    /// `y = x`
+    Linear,
+    /// `y = x²`
+    EaseIn,
+    /// `y = 1 - (1 - x)²`
+    EaseOut,
+    /// `y = 3x² - 2x³`
+    Smoothstep,
+    /// `y = (1 - cos(πx)) / 2`, approximated
+    Sine,
+}
+
+impl CurvePreset {
+    /// All available presets
+    pub const ALL: [CurvePreset; 5] = [
+        CurvePreset::Linear,
+        CurvePreset::EaseIn,
+        CurvePreset::EaseOut,
+        CurvePreset::Smoothstep,
+        CurvePreset::Sine,
+    ];
+
+    /// Display name of the preset
+    pub fn name(&self) -> &'static str {
+        match self {
+            CurvePreset::Linear => "Linear",
+            CurvePreset::EaseIn => "Ease in",
+            CurvePreset::EaseOut => "Ease out",
+            CurvePreset::Smoothstep => "Smoothstep",
+            CurvePreset::Sine => "Sine",
+        }
+    }
+
+    /// Returns the knots of the preset
+    pub fn knots(&self) -> Vec<Knot> {
+        let cubic = |position: Vec2, slope: f32| Knot {
+            position,
+            interpolation: KnotInterpolation::Cubic,
+            left_tangent: Tangent {
+                slope,
+                ..Default::default()
+            },
+            right_tangent: Tangent {
+                slope,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        match self {
+            CurvePreset::Linear => vec![
+                Knot {
+                    position: Vec2::ZERO,
+                    ..Default::default()
+                },
+                Knot {
+                    position: Vec2::ONE,
+                    ..Default::default()
+                },
+            ],
+            CurvePreset::EaseIn => vec![cubic(Vec2::ZERO, 0.0), cubic(Vec2::ONE, 2.0)],
+            CurvePreset::EaseOut => vec![cubic(Vec2::ZERO, 2.0), cubic(Vec2::ONE, 0.0)],
+            CurvePreset::Smoothstep => vec![cubic(Vec2::ZERO, 0.0), cubic(Vec2::ONE, 0.0)],
+            CurvePreset::Sine => vec![
+                cubic(Vec2::ZERO, 0.0),
+                cubic(Vec2::splat(0.5), PI / 2.0),
+                cubic(Vec2::ONE, 0.0),
+            ],
+        }
+    }
+
+    /// Constructs a [LookupCurve] from the preset, named after the preset
+    pub fn to_curve(&self) -> LookupCurve {
+        LookupCurve::new_unchecked(self.knots()).with_name(self.name())
+    }
+}
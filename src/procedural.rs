@@ -0,0 +1,162 @@
+use bevy_math::Vec2;
+use std::f32::consts::TAU;
+
+use crate::{Knot, KnotInterpolation, LookupCurve};
+
+impl LookupCurve {
+    /// Constructs a curve approximating `y = amplitude * sin(TAU * frequency * x + phase)` between `x_start` and `x_end`.
+    ///
+    /// The function is sampled at `num_knots` evenly spaced points (at least two), connected by [KnotInterpolation::Cubic] segments
+    /// with tangents from [LookupCurve::catmull_rom_slope].
+    ///
+    /// # Panics
+    /// Panics if `x_start` or `x_end` is not finite, or if `x_end` is not far enough above `x_start` to fit `num_knots` knots
+    /// with distinct x-positions (see [LookupCurve::KNOT_X_EPSILON]).
+    pub fn from_sine(
+        frequency: f32,
+        amplitude: f32,
+        phase: f32,
+        x_start: f32,
+        x_end: f32,
+        num_knots: usize,
+    ) -> LookupCurve {
+        Self::from_samples(x_start, x_end, num_knots, |x| {
+            amplitude * (TAU * frequency * x + phase).sin()
+        })
+    }
+
+    /// Constructs a curve approximating `y = amplitude * cos(TAU * frequency * x + phase)` between `x_start` and `x_end`, see [LookupCurve::from_sine].
+    pub fn from_cosine(
+        frequency: f32,
+        amplitude: f32,
+        phase: f32,
+        x_start: f32,
+        x_end: f32,
+        num_knots: usize,
+    ) -> LookupCurve {
+        Self::from_samples(x_start, x_end, num_knots, |x| {
+            amplitude * (TAU * frequency * x + phase).cos()
+        })
+    }
+
+    /// Constructs a curve from one-dimensional Perlin noise in the range `[-1, 1]` between `x_start` and `x_end`, see [LookupCurve::from_sine].
+    ///
+    /// `frequency` is the number of noise cells per unit of x. The same `seed` always produces the same curve.
+    pub fn from_perlin_noise(
+        seed: u64,
+        frequency: f32,
+        x_start: f32,
+        x_end: f32,
+        num_knots: usize,
+    ) -> LookupCurve {
+        Self::from_samples(x_start, x_end, num_knots, |x| {
+            perlin_noise(seed, x * frequency)
+        })
+    }
+
+    /// Samples `f` at `num_knots` evenly spaced points and connects them with Catmull-Rom cubic segments, see [LookupCurve::from_sine]
+    fn from_samples(
+        x_start: f32,
+        x_end: f32,
+        num_knots: usize,
+        f: impl Fn(f32) -> f32,
+    ) -> LookupCurve {
+        let num_knots = num_knots.max(2);
+        let step = (x_end - x_start) / (num_knots - 1) as f32;
+        assert!(
+            x_start.is_finite() && x_end.is_finite() && step >= Self::KNOT_X_EPSILON,
+            "x_start..x_end must be a finite range with room for num_knots distinct knots"
+        );
+        let knots = (0..num_knots)
+            .map(|i| {
+                let x = x_start + step * i as f32;
+                Knot {
+                    position: Vec2::new(x, f(x)),
+                    interpolation: KnotInterpolation::Cubic,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        let mut curve = LookupCurve::new_unchecked(knots);
+        curve.apply_catmull_rom_tangents();
+        curve
+    }
+}
+
+/// Gradient in `[-1, 1]` for the lattice point `i`, from a SplitMix64 hash of the seed and the point
+fn gradient(seed: u64, i: i64) -> f32 {
+    let mut z = seed ^ (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+}
+
+/// One-dimensional Perlin noise in `[-1, 1]`, zero at integer values of `x`
+fn perlin_noise(seed: u64, x: f32) -> f32 {
+    let cell = x.floor();
+    let t = x - cell;
+    let i = cell as i64;
+
+    let n0 = gradient(seed, i) * t;
+    let n1 = gradient(seed, i + 1) * (t - 1.0);
+    let fade = t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+
+    // The unscaled noise is within [-0.5, 0.5]
+    2.0 * (n0 + (n1 - n0) * fade)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sine_and_cosine_follow_functions() {
+        let sine = LookupCurve::from_sine(2.0, 0.5, 0.3, -1.0, 1.0, 81);
+        let cosine = LookupCurve::from_cosine(2.0, 0.5, 0.3, -1.0, 1.0, 81);
+        assert_eq!(sine.knots().len(), 81);
+        assert_eq!(sine.domain(), (-1.0, 1.0));
+        for i in 0..=200 {
+            let x = -1.0 + i as f32 * 0.01;
+            let angle = TAU * 2.0 * x + 0.3;
+            assert!((sine.lookup(x) - 0.5 * angle.sin()).abs() < 0.01);
+            assert!((cosine.lookup(x) - 0.5 * angle.cos()).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn perlin_noise_is_deterministic_and_bounded() {
+        let curve = LookupCurve::from_perlin_noise(42, 4.0, 0.0, 10.0, 200);
+        let same = LookupCurve::from_perlin_noise(42, 4.0, 0.0, 10.0, 200);
+        let other = LookupCurve::from_perlin_noise(7, 4.0, 0.0, 10.0, 200);
+
+        assert!(curve
+            .knots()
+            .iter()
+            .zip(same.knots())
+            .all(|(a, b)| a.position == b.position));
+        assert!(curve
+            .knots()
+            .iter()
+            .zip(other.knots())
+            .any(|(a, b)| a.position != b.position));
+        assert!(curve
+            .knots()
+            .iter()
+            .all(|knot| (-1.0..=1.0).contains(&knot.position.y)));
+        assert!(curve.knots().iter().any(|knot| knot.position.y.abs() > 0.1));
+    }
+
+    #[test]
+    #[should_panic(expected = "room for num_knots")]
+    fn empty_range_is_rejected() {
+        LookupCurve::from_sine(1.0, 1.0, 0.0, 1.0, 1.0, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "room for num_knots")]
+    fn reversed_range_is_rejected() {
+        LookupCurve::from_cosine(1.0, 1.0, 0.0, 1.0, -1.0, 41);
+    }
+}
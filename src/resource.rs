@@ -0,0 +1,86 @@
+use std::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use bevy_ecs::system::Resource;
+
+use crate::LookupCurve;
+
+/// A [LookupCurve] stored directly as a Bevy [Resource], for prototypes or games that do not load curves as assets.
+///
+/// `LookupCurve` is a resource itself, but only one can be inserted into a world that way.
+/// The marker type `T` makes it possible to store several curves side by side:
+///
+/// ```
+/// # use bevy_lookup_curve::{LookupCurve, LookupCurveResource};
+/// struct JumpHeight;
+/// struct FallSpeed;
+///
+/// let mut world = bevy_ecs::world::World::new();
+/// world.insert_resource(LookupCurveResource::<JumpHeight>::new(LookupCurve::default()));
+/// world.insert_resource(LookupCurveResource::<FallSpeed>::new(LookupCurve::default()));
+///
+/// let jump_height = world.resource::<LookupCurveResource<JumpHeight>>();
+/// assert_eq!(jump_height.lookup(0.5), 0.0);
+///
+/// // The marker types do not need to implement any traits
+/// let copy = jump_height.clone();
+/// println!("{:?}", copy);
+/// ```
+#[derive(Resource)]
+pub struct LookupCurveResource<T: Send + Sync + 'static = ()> {
+    pub curve: LookupCurve,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Send + Sync + 'static> LookupCurveResource<T> {
+    pub fn new(curve: LookupCurve) -> Self {
+        Self {
+            curve,
+            marker: PhantomData,
+        }
+    }
+
+    /// Find y for given x on the curve, see [LookupCurve::lookup]
+    #[inline]
+    pub fn lookup(&self, x: f32) -> f32 {
+        self.curve.lookup(x)
+    }
+}
+
+// Implemented manually, as deriving would require `T` to implement the traits as well
+
+impl<T: Send + Sync + 'static> std::fmt::Debug for LookupCurveResource<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LookupCurveResource")
+            .field("curve", &self.curve)
+            .finish()
+    }
+}
+
+impl<T: Send + Sync + 'static> Clone for LookupCurveResource<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.curve.clone())
+    }
+}
+
+impl<T: Send + Sync + 'static> Default for LookupCurveResource<T> {
+    fn default() -> Self {
+        Self::new(LookupCurve::default())
+    }
+}
+
+impl<T: Send + Sync + 'static> Deref for LookupCurveResource<T> {
+    type Target = LookupCurve;
+
+    fn deref(&self) -> &Self::Target {
+        &self.curve
+    }
+}
+
+impl<T: Send + Sync + 'static> DerefMut for LookupCurveResource<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.curve
+    }
+}
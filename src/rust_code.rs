@@ -0,0 +1,120 @@
+use bevy_math::Vec2;
+use std::fmt::Write;
+
+use crate::{KnotInterpolation, LookupCurve, Tangent, TangentMode};
+
+fn vec2_expr(v: Vec2) -> String {
+    format!("bevy::math::Vec2::new({:?}, {:?})", v.x, v.y)
+}
+
+fn tangent_expr(tangent: &Tangent) -> String {
+    let mode = match tangent.mode {
+        TangentMode::Free => "Free",
+        TangentMode::Aligned => "Aligned",
+    };
+    format!(
+        "bevy_lookup_curve::Tangent {{ slope: {:?}, mode: bevy_lookup_curve::TangentMode::{}, weight: {:?} }}",
+        tangent.slope, mode, tangent.weight
+    )
+}
+
+fn interpolation_expr(interpolation: &KnotInterpolation) -> String {
+    match interpolation {
+        KnotInterpolation::Constant => "bevy_lookup_curve::KnotInterpolation::Constant".into(),
+        KnotInterpolation::Linear => "bevy_lookup_curve::KnotInterpolation::Linear".into(),
+        KnotInterpolation::Cubic => "bevy_lookup_curve::KnotInterpolation::Cubic".into(),
+        KnotInterpolation::Cardinal => "bevy_lookup_curve::KnotInterpolation::Cardinal".into(),
+        KnotInterpolation::BSpline => "bevy_lookup_curve::KnotInterpolation::BSpline".into(),
+        KnotInterpolation::HermiteRaw {
+            tangent_in,
+            tangent_out,
+        } => format!(
+            "bevy_lookup_curve::KnotInterpolation::HermiteRaw {{ tangent_in: {}, tangent_out: {} }}",
+            vec2_expr(*tangent_in),
+            vec2_expr(*tangent_out)
+        ),
+    }
+}
+
+impl LookupCurve {
+    /// Generates a Rust expression constructing the knots of this curve, for hardcoding a curve prototyped in the editor.
+    ///
+    /// All paths are fully qualified, so the expression compiles without `use` statements in a crate depending on `bevy`.
+    /// Replace `bevy::math` with `bevy_math` when only depending on the math crate.
+    ///
+    /// Knot positions, interpolations and tangents are included. Other properties of the curve, like [LookupCurve::tension], are not.
+    pub fn to_rust_code(&self) -> String {
+        let mut code = String::from("bevy_lookup_curve::LookupCurve::with_knots(vec![\n");
+        for knot in &self.knots {
+            writeln!(code, "    bevy_lookup_curve::Knot {{").unwrap();
+            writeln!(code, "        position: {},", vec2_expr(knot.position)).unwrap();
+            writeln!(
+                code,
+                "        interpolation: {},",
+                interpolation_expr(&knot.interpolation)
+            )
+            .unwrap();
+            writeln!(
+                code,
+                "        left_tangent: {},",
+                tangent_expr(&knot.left_tangent)
+            )
+            .unwrap();
+            writeln!(
+                code,
+                "        right_tangent: {},",
+                tangent_expr(&knot.right_tangent)
+            )
+            .unwrap();
+            if knot.locked {
+                writeln!(code, "        locked: true,").unwrap();
+            }
+            if knot.anchor {
+                writeln!(code, "        anchor: true,").unwrap();
+            }
+            writeln!(code, "        ..Default::default()").unwrap();
+            writeln!(code, "    }},").unwrap();
+        }
+        code.push_str("])\n.unwrap()");
+        code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Knot;
+
+    #[test]
+    fn rust_code_contains_knot_data() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.5),
+                interpolation: KnotInterpolation::Cubic,
+                right_tangent: Tangent {
+                    slope: 1.5,
+                    mode: TangentMode::Free,
+                    weight: Some(0.25),
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 1.0),
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        let code = curve.to_rust_code();
+        assert!(code.starts_with("bevy_lookup_curve::LookupCurve::with_knots(vec!["));
+        assert!(code.ends_with("])\n.unwrap()"));
+        assert_eq!(code.matches("bevy_lookup_curve::Knot {").count(), 2);
+        assert!(code.contains("position: bevy::math::Vec2::new(0.0, 0.5),"));
+        assert!(code.contains("interpolation: bevy_lookup_curve::KnotInterpolation::Cubic,"));
+        assert!(code.contains(
+            "right_tangent: bevy_lookup_curve::Tangent { slope: 1.5, mode: bevy_lookup_curve::TangentMode::Free, weight: Some(0.25) },"
+        ));
+        assert!(code.contains("interpolation: bevy_lookup_curve::KnotInterpolation::Linear,"));
+    }
+}
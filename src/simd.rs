@@ -0,0 +1,135 @@
+use bevy_math::Vec4;
+
+use crate::{KnotInterpolation, LookupCurve};
+
+impl LookupCurve {
+    /// Find y for four values of x at once.
+    ///
+    /// The knot search is done for each x separately, while [KnotInterpolation::Constant], [KnotInterpolation::Linear], [KnotInterpolation::Cardinal]
+    /// and unweighted [KnotInterpolation::Cubic] segments are evaluated together as a single vectorized hermite polynomial.
+    /// Lanes in other segments fall back to [LookupCurve::lookup]. Results may differ from [LookupCurve::lookup] within floating point precision.
+    pub fn lookup_simd4(&self, xs: [f32; 4]) -> [f32; 4] {
+        let mut p0 = [[0.0; 4]; 2];
+        let mut p1 = [[0.0; 4]; 2];
+        let mut slopes = [[0.0; 4]; 2];
+        let mut fallback = [false; 4];
+
+        for (lane, x) in xs.into_iter().enumerate() {
+            let Some(i) = self.segment_index(x, None) else {
+                // Flat outside of the knot range
+                let y = self.outside_value(x);
+                (p0[0][lane], p0[1][lane]) = (x, y);
+                (p1[0][lane], p1[1][lane]) = (x + 1.0, y);
+                continue;
+            };
+
+            let (knot_a, knot_b) = (&self.knots[i], &self.knots[i + 1]);
+            let (a, mut b) = (knot_a.position, knot_b.position);
+            let lane_slopes = match knot_a.interpolation {
+                KnotInterpolation::Constant => {
+                    b.y = a.y;
+                    (0.0, 0.0)
+                }
+                KnotInterpolation::Linear => {
+                    let slope = (b.y - a.y) / (b.x - a.x);
+                    (slope, slope)
+                }
+                KnotInterpolation::Cubic
+                    if knot_a.right_tangent.weight.is_none()
+                        && knot_b.left_tangent.weight.is_none() =>
+                {
+                    (knot_a.right_tangent.slope, knot_b.left_tangent.slope)
+                }
+                KnotInterpolation::Cardinal => (self.cardinal_slope(i), self.cardinal_slope(i + 1)),
+                _ => {
+                    fallback[lane] = true;
+                    (p0[0][lane], p1[0][lane]) = (x, x + 1.0);
+                    continue;
+                }
+            };
+            (p0[0][lane], p0[1][lane]) = (a.x, a.y);
+            (p1[0][lane], p1[1][lane]) = (b.x, b.y);
+            (slopes[0][lane], slopes[1][lane]) = lane_slopes;
+        }
+
+        let mut ys = hermite_interp4(
+            (Vec4::from_array(p0[0]), Vec4::from_array(p0[1])),
+            (Vec4::from_array(p1[0]), Vec4::from_array(p1[1])),
+            Vec4::from_array(slopes[0]),
+            Vec4::from_array(slopes[1]),
+            Vec4::from_array(xs),
+        )
+        .to_array();
+
+        for lane in (0..4).filter(|lane| fallback[*lane]) {
+            ys[lane] = self.lookup_unclamped(xs[lane], None);
+        }
+        match self.output_clamp {
+            Some((min, max)) => ys.map(|y| y.max(min).min(max)),
+            None => ys,
+        }
+    }
+}
+
+/// Four-lane version of [crate::hermite_interp], with the points given as separate x and y vectors
+#[inline]
+fn hermite_interp4(
+    (p0_x, p0_y): (Vec4, Vec4),
+    (p1_x, p1_y): (Vec4, Vec4),
+    slope0: Vec4,
+    slope1: Vec4,
+    x: Vec4,
+) -> Vec4 {
+    let dx = p1_x - p0_x;
+    let x = (x - p0_x) / dx;
+    let m0 = slope0 * dx;
+    let m1 = slope1 * dx;
+
+    let x2 = x * x;
+    let x3 = x2 * x;
+
+    let a = 2. * x3 - 3. * x2 + 1.;
+    let b = x3 - 2. * x2 + x;
+    let c = x3 - x2;
+    let d = -2. * x3 + 3. * x2;
+
+    a * p0_y + b * m0 + c * m1 + d * p1_y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Knot, Tangent};
+    use bevy_math::Vec2;
+
+    #[test]
+    fn matches_scalar_lookup() {
+        let knot = |x: f32, y: f32, interpolation: KnotInterpolation, weight: Option<f32>| Knot {
+            position: Vec2::new(x, y),
+            interpolation,
+            right_tangent: Tangent {
+                slope: 1.5,
+                weight,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let curve = LookupCurve::with_knots(vec![
+            knot(0.0, 0.0, KnotInterpolation::Linear, None),
+            knot(1.0, 1.0, KnotInterpolation::Cubic, None),
+            knot(2.0, 0.5, KnotInterpolation::Constant, None),
+            knot(3.0, 2.0, KnotInterpolation::Cardinal, None),
+            knot(4.0, 0.0, KnotInterpolation::Cubic, Some(0.8)),
+            knot(5.0, 1.0, KnotInterpolation::Linear, None),
+        ])
+        .unwrap();
+
+        for i in 0..30 {
+            let xs = [-1.0, 0.3, 1.7, 2.5].map(|x| x + i as f32 * 0.2);
+            let ys = curve.lookup_simd4(xs);
+            for (x, y) in xs.into_iter().zip(ys) {
+                assert!((y - curve.lookup(x)).abs() < 1e-5, "x = {x}");
+            }
+        }
+    }
+}
@@ -0,0 +1,444 @@
+use bevy_math::Vec2;
+use std::fmt::Write;
+
+use crate::{tangents_from_bezier, Knot, KnotInterpolation, LookupCurve};
+
+/// Error returned by [LookupCurve::from_svg_path]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SvgParseError {
+    /// The path did not start with an `M` command
+    MissingMoveTo,
+    /// The path contains a command that is not supported. Only absolute `M`, `L`, `C` and `Z` commands are supported.
+    UnsupportedCommand(char),
+    /// A command did not have the expected number of arguments
+    MissingArguments(char),
+    /// A number in the path could not be parsed
+    InvalidNumber(String),
+    /// The path moves backwards along the x-axis, which can not be represented by a [LookupCurve]
+    NonMonotoneX,
+}
+
+impl std::fmt::Display for SvgParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingMoveTo => write!(f, "SVG path must start with an M command"),
+            Self::UnsupportedCommand(c) => write!(f, "Unsupported SVG path command: {}", c),
+            Self::MissingArguments(c) => write!(f, "Missing arguments for SVG path command: {}", c),
+            Self::InvalidNumber(s) => write!(f, "Invalid number in SVG path: {}", s),
+            Self::NonMonotoneX => write!(f, "SVG path must not move backwards along the x-axis"),
+        }
+    }
+}
+
+impl std::error::Error for SvgParseError {}
+
+enum Token {
+    Command(char),
+    Number(f32),
+}
+
+fn flush_number(number: &mut String, tokens: &mut Vec<Token>) -> Result<(), SvgParseError> {
+    if !number.is_empty() {
+        let value = number
+            .parse::<f32>()
+            .map_err(|_| SvgParseError::InvalidNumber(number.clone()))?;
+        tokens.push(Token::Number(value));
+        number.clear();
+    }
+    Ok(())
+}
+
+fn tokenize(d: &str) -> Result<Vec<Token>, SvgParseError> {
+    let mut tokens = Vec::new();
+    let mut number = String::new();
+
+    for c in d.chars() {
+        match c {
+            '0'..='9' | '.' | 'e' | 'E' => number.push(c),
+            '-' | '+' => {
+                if !number.ends_with(['e', 'E']) {
+                    flush_number(&mut number, &mut tokens)?;
+                }
+                number.push(c);
+            }
+            c if c.is_whitespace() || c == ',' => flush_number(&mut number, &mut tokens)?,
+            c if c.is_ascii_alphabetic() => {
+                flush_number(&mut number, &mut tokens)?;
+                tokens.push(Token::Command(c));
+            }
+            c => return Err(SvgParseError::UnsupportedCommand(c)),
+        }
+    }
+    flush_number(&mut number, &mut tokens)?;
+
+    Ok(tokens)
+}
+
+/// Parses the `viewBox` attribute of an `<svg>` element in `svg` as `(min_x, min_y, width, height)`
+fn parse_view_box(svg: &str) -> Option<(f32, f32, f32, f32)> {
+    let (_, rest) = svg.split_once("viewBox=\"")?;
+    let values: Vec<f32> = rest
+        .split('"')
+        .next()?
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse().ok())
+        .collect::<Option<_>>()?;
+    match values[..] {
+        [min_x, min_y, width, height] => Some((min_x, min_y, width, height)),
+        _ => None,
+    }
+}
+
+/// Returns the contents of the `d` attribute in `svg`, or `svg` itself if it does not contain one
+fn path_data(svg: &str) -> &str {
+    svg.match_indices("d=\"")
+        .find(|(i, _)| *i == 0 || svg[..*i].ends_with(char::is_whitespace))
+        .map(|(i, attribute)| {
+            svg[i + attribute.len()..]
+                .split('"')
+                .next()
+                .unwrap_or_default()
+        })
+        .unwrap_or(svg)
+}
+
+impl LookupCurve {
+    /// Exports the curve as an SVG `<path>` element.
+    ///
+    /// `view_box` is `(min_x, min_y, width, height)` in SVG viewport coordinates, meant to be used as the `viewBox` of the containing `<svg>` element.
+    /// The curve is scaled to fill the view box: its domain is mapped to `min_x..min_x + width`, and the vertical extent of its knots and bezier control points to `min_y..min_y + height`.
+    /// The y-axis is flipped, as it points down in SVG, so that the curve is displayed upright.
+    ///
+    /// Segments are emitted as `L` (linear), `C` (cubic) or two `L` commands forming a step (constant).
+    pub fn to_svg_path(&self, view_box: (f32, f32, f32, f32)) -> String {
+        let (min_x, min_y, width, height) = view_box;
+        let (bounds_min, bounds_max) = self.svg_bounds();
+        let size = bounds_max - bounds_min;
+        let scale = |size: f32, view_size: f32| if size > 0.0 { view_size / size } else { 0.0 };
+        let scale = Vec2::new(scale(size.x, width), scale(size.y, height));
+        let to_svg = |p: Vec2| {
+            let p = (p - bounds_min) * scale;
+            Vec2::new(min_x + p.x, min_y + height - p.y)
+        };
+
+        let mut d = String::new();
+        if let Some(first) = self.knots.first() {
+            let p = to_svg(first.position);
+            write!(d, "M {} {}", p.x, p.y).unwrap();
+        }
+
+        for (i, w) in self.knots.windows(2).enumerate() {
+            let (knot_a, knot_b) = (&w[0], &w[1]);
+            match knot_a.interpolation {
+                KnotInterpolation::Constant => {
+                    let step = to_svg(Vec2::new(knot_b.position.x, knot_a.position.y));
+                    let p = to_svg(knot_b.position);
+                    write!(d, " L {} {} L {} {}", step.x, step.y, p.x, p.y).unwrap();
+                }
+                KnotInterpolation::Linear => {
+                    let p = to_svg(knot_b.position);
+                    write!(d, " L {} {}", p.x, p.y).unwrap();
+                }
+                KnotInterpolation::Cubic
+                | KnotInterpolation::Cardinal
+                | KnotInterpolation::BSpline
+                | KnotInterpolation::HermiteRaw { .. } => {
+                    let [_, c1, c2, p] = self.segment_bezier(i).map(to_svg);
+                    write!(d, " C {} {} {} {} {} {}", c1.x, c1.y, c2.x, c2.y, p.x, p.y).unwrap();
+                }
+            }
+        }
+
+        format!("<path d=\"{}\"/>", d)
+    }
+
+    /// Bounding box `(min, max)` of the knots and the bezier control points of the segments, which contains the whole curve
+    fn svg_bounds(&self) -> (Vec2, Vec2) {
+        let control_points = (0..self.knots.len().saturating_sub(1)).flat_map(|i| {
+            match self.knots[i].interpolation {
+                KnotInterpolation::Constant | KnotInterpolation::Linear => Vec::new(),
+                _ => self.segment_bezier(i)[1..3].to_vec(),
+            }
+        });
+        self.knots
+            .iter()
+            .map(|knot| knot.position)
+            .chain(control_points)
+            .fold(None, |bounds: Option<(Vec2, Vec2)>, p| match bounds {
+                Some((min, max)) => Some((min.min(p), max.max(p))),
+                None => Some((p, p)),
+            })
+            .unwrap_or_default()
+    }
+
+    /// Constructs a [LookupCurve] from SVG path data, for example authored in a vector graphics tool.
+    ///
+    /// Accepts the contents of a `d` attribute, a full `<path d="..."/>` element, or an `<svg>` element containing the path.
+    /// Only absolute `M`, `L`, `C` and `Z` commands are supported. `M` creates the initial knot, `L` creates a [KnotInterpolation::Linear] segment
+    /// and `C` creates a [KnotInterpolation::Cubic] segment with weighted tangents. `Z` is ignored, as a lookup curve can not be closed.
+    ///
+    /// As the y-axis points down in SVG, y-coordinates are flipped. If `path_str` has a `viewBox` attribute, they are flipped within its vertical extent,
+    /// so that a path exported with [LookupCurve::to_svg_path] into the curve's own bounds is imported unchanged. Otherwise they are negated.
+    pub fn from_svg_path(path_str: &str) -> Result<LookupCurve, SvgParseError> {
+        let flip_y = match parse_view_box(path_str) {
+            Some((_, min_y, _, height)) => 2.0 * min_y + height,
+            None => 0.0,
+        };
+
+        let tokens = tokenize(path_data(path_str))?;
+        let mut tokens = tokens.iter().peekable();
+        let mut knots: Vec<Knot> = Vec::new();
+        let mut command = None;
+
+        while let Some(token) = tokens.peek() {
+            // Numbers without a preceding command repeat the last command, or an L after M
+            let c = match token {
+                Token::Command(c) => {
+                    tokens.next();
+                    *c
+                }
+                Token::Number(_) => match command {
+                    Some('M') => 'L',
+                    Some(c) => c,
+                    None => return Err(SvgParseError::MissingMoveTo),
+                },
+            };
+
+            let arg_count = match c {
+                'M' | 'L' => 2,
+                'C' => 6,
+                'Z' => 0,
+                c => return Err(SvgParseError::UnsupportedCommand(c)),
+            };
+            let mut args = [0.0; 6];
+            for arg in args.iter_mut().take(arg_count) {
+                match tokens.next() {
+                    Some(Token::Number(v)) => *arg = *v,
+                    _ => return Err(SvgParseError::MissingArguments(c)),
+                }
+            }
+            for y in args.iter_mut().take(arg_count).skip(1).step_by(2) {
+                *y = flip_y - *y;
+            }
+
+            let prev = knots.last().map(|k| k.position);
+            match (c, prev) {
+                ('M', None) => knots.push(Knot {
+                    position: Vec2::new(args[0], args[1]),
+                    ..Default::default()
+                }),
+                ('M', Some(_)) => return Err(SvgParseError::UnsupportedCommand(c)),
+                (_, None) => return Err(SvgParseError::MissingMoveTo),
+                ('L', Some(p0)) => {
+                    let p = Vec2::new(args[0], args[1]);
+                    if p.x <= p0.x {
+                        return Err(SvgParseError::NonMonotoneX);
+                    }
+                    knots.last_mut().unwrap().interpolation = KnotInterpolation::Linear;
+                    knots.push(Knot {
+                        position: p,
+                        ..Default::default()
+                    });
+                }
+                ('C', Some(p0)) => {
+                    let [c1, c2, p] = [
+                        Vec2::new(args[0], args[1]),
+                        Vec2::new(args[2], args[3]),
+                        Vec2::new(args[4], args[5]),
+                    ];
+                    if p.x <= p0.x || [c1.x, c2.x].iter().any(|x| *x < p0.x || *x > p.x) {
+                        return Err(SvgParseError::NonMonotoneX);
+                    }
+
+                    let (right_tangent, left_tangent) = tangents_from_bezier([p0, c1, c2, p]);
+
+                    let knot_a = knots.last_mut().unwrap();
+                    knot_a.interpolation = KnotInterpolation::Cubic;
+                    knot_a.right_tangent = right_tangent;
+                    knots.push(Knot {
+                        position: p,
+                        left_tangent,
+                        ..Default::default()
+                    });
+                }
+                _ => {}
+            }
+
+            // Numbers after Z can not repeat it, as it takes no arguments
+            command = if c == 'Z' { None } else { Some(c) };
+        }
+
+        if knots.is_empty() {
+            return Err(SvgParseError::MissingMoveTo);
+        }
+
+        Ok(LookupCurve::new_unchecked(knots))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Knot, Tangent};
+
+    /// Minimal parser for absolute, space separated path commands
+    fn parse_path(path: &str) -> Vec<(char, Vec<f32>)> {
+        let d = path
+            .strip_prefix("<path d=\"")
+            .and_then(|s| s.strip_suffix("\"/>"))
+            .unwrap();
+
+        let mut commands: Vec<(char, Vec<f32>)> = Vec::new();
+        for token in d.split_whitespace() {
+            match token.parse::<f32>() {
+                Ok(v) => commands.last_mut().unwrap().1.push(v),
+                Err(_) => commands.push((token.chars().next().unwrap(), Vec::new())),
+            }
+        }
+        commands
+    }
+
+    #[test]
+    fn svg_path_matches_bezier() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot {
+                position: Vec2::new(0.0, 0.0),
+                interpolation: KnotInterpolation::Cubic,
+                right_tangent: Tangent {
+                    slope: 1.0,
+                    weight: Some(0.5),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(0.5, 0.8),
+                interpolation: KnotInterpolation::Linear,
+                left_tangent: Tangent {
+                    slope: -0.5,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Knot {
+                position: Vec2::new(1.0, 0.2),
+                ..Default::default()
+            },
+        ])
+        .unwrap();
+
+        // The curve spans x in [0, 1], and y from 0 up to the second control point of the cubic segment
+        let y_max = curve.knots()[0].compute_bezier_to(&curve.knots()[1])[2].y;
+        assert!(y_max > 0.8);
+        let commands = parse_path(&curve.to_svg_path((10.0, 20.0, 200.0, 100.0)));
+        let flip = |p: Vec2| Vec2::new(10.0 + 200.0 * p.x, 120.0 - 100.0 * p.y / y_max);
+
+        assert_eq!(commands.len(), 3);
+
+        assert_eq!(commands[0].0, 'M');
+        assert_eq!(commands[0].1, [10.0, 120.0]);
+
+        let knots = curve.knots();
+        let [_, c1, c2, p] = knots[0].compute_bezier_to(&knots[1]).map(flip);
+        assert_eq!(commands[1].0, 'C');
+        for (a, b) in commands[1].1.iter().zip([c1.x, c1.y, c2.x, c2.y, p.x, p.y]) {
+            assert!((a - b).abs() < 1e-4);
+        }
+
+        let p = flip(knots[2].position);
+        assert_eq!(commands[2].0, 'L');
+        assert!((commands[2].1[0] - p.x).abs() < 1e-4);
+        assert!((commands[2].1[1] - p.y).abs() < 1e-4);
+    }
+
+    #[test]
+    fn svg_path_import() {
+        let curve = LookupCurve::from_svg_path(
+            r#"<svg viewBox="0 0 2 1"><path id="curve" d="M0,1 C0.25,1 0.5,0 1,0 L2,0.5 Z"/></svg>"#,
+        )
+        .unwrap();
+        let knots = curve.knots();
+        assert_eq!(knots.len(), 3);
+        assert!(matches!(knots[0].interpolation, KnotInterpolation::Cubic));
+        assert!(matches!(knots[1].interpolation, KnotInterpolation::Linear));
+        assert_eq!(
+            knots[0].compute_bezier_to(&knots[1]),
+            [
+                Vec2::new(0.0, 0.0),
+                Vec2::new(0.25, 0.0),
+                Vec2::new(0.5, 1.0),
+                Vec2::new(1.0, 1.0)
+            ]
+        );
+        assert_eq!(curve.lookup(1.5), 0.75);
+
+        // Without a viewBox, y is negated
+        let curve = LookupCurve::from_svg_path("M 0 1 L 1 -2").unwrap();
+        assert_eq!(curve.knots()[0].position, Vec2::new(0.0, -1.0));
+        assert_eq!(curve.knots()[1].position, Vec2::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn svg_path_round_trip() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.5)
+                .with_interpolation(KnotInterpolation::Cubic)
+                .with_slope(2.0),
+            Knot::at(1.0, 2.0)
+                .with_interpolation(KnotInterpolation::Cubic)
+                .with_weights(Some(0.2), Some(0.6)),
+            Knot::at(2.0, 1.0).with_interpolation(KnotInterpolation::Linear),
+            Knot::at(3.0, 0.0),
+        ])
+        .unwrap();
+
+        // Exported into the bounds of the curve, including the control points of the cubic segments
+        let (min, max) = curve.svg_bounds();
+        let view_box = (min.x, min.y, max.x - min.x, max.y - min.y);
+        let svg = format!(
+            "<svg viewBox=\"{} {} {} {}\">{}</svg>",
+            view_box.0,
+            view_box.1,
+            view_box.2,
+            view_box.3,
+            curve.to_svg_path(view_box)
+        );
+        let imported = LookupCurve::from_svg_path(&svg).unwrap();
+        assert_eq!(imported.knots().len(), 4);
+        assert!(imported.max_deviation_from(&curve, 300) < 1e-4);
+    }
+
+    #[test]
+    fn svg_path_import_errors() {
+        assert_eq!(
+            LookupCurve::from_svg_path("L 1 1").unwrap_err(),
+            SvgParseError::MissingMoveTo
+        );
+        assert_eq!(
+            LookupCurve::from_svg_path("M 0 0 l 1 1").unwrap_err(),
+            SvgParseError::UnsupportedCommand('l')
+        );
+        assert_eq!(
+            LookupCurve::from_svg_path("M 0 0 L 1").unwrap_err(),
+            SvgParseError::MissingArguments('L')
+        );
+        assert_eq!(
+            LookupCurve::from_svg_path("M 1 0 L 0 1").unwrap_err(),
+            SvgParseError::NonMonotoneX
+        );
+        assert_eq!(
+            LookupCurve::from_svg_path("M 0 0 L 0 1").unwrap_err(),
+            SvgParseError::NonMonotoneX
+        );
+        assert_eq!(
+            LookupCurve::from_svg_path("M 0 0 C 0 1 0 1 0 1").unwrap_err(),
+            SvgParseError::NonMonotoneX
+        );
+        assert_eq!(
+            LookupCurve::from_svg_path("M 0 0 L 1 1 Z 2").unwrap_err(),
+            SvgParseError::MissingMoveTo
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt::Write;
+
+use crate::{Knot, KnotInterpolation, LookupCache, LookupCurve};
+
+/// Error returned by [LookupCurve::from_unreal_curve_csv]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The CSV does not contain a header row
+    MissingHeader,
+    /// The CSV does not contain a curve row after the header
+    MissingCurve,
+    /// A value in the CSV could not be parsed as a number
+    InvalidNumber(String),
+    /// The curve row does not have one value per x-value in the header
+    LengthMismatch { expected: usize, found: usize },
+    /// The x-values in the header are not strictly increasing
+    NonIncreasingX,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingHeader => write!(f, "Unreal curve CSV is missing the header row"),
+            Self::MissingCurve => write!(f, "Unreal curve CSV does not contain a curve row"),
+            Self::InvalidNumber(s) => write!(f, "Invalid number in Unreal curve CSV: {}", s),
+            Self::LengthMismatch { expected, found } => write!(
+                f,
+                "Expected {} values in Unreal curve CSV row, found {}",
+                expected, found
+            ),
+            Self::NonIncreasingX => write!(f, "Unreal curve CSV x-values must be increasing"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Row name used when the curve has no name
+const DEFAULT_ROW_NAME: &str = "CurveKey";
+
+fn parse_values<'a>(values: impl Iterator<Item = &'a str>) -> Result<Vec<f32>, ParseError> {
+    values
+        .map(|value| {
+            let value = value.trim();
+            value
+                .parse()
+                .map_err(|_| ParseError::InvalidNumber(value.to_string()))
+        })
+        .collect()
+}
+
+impl LookupCurve {
+    /// Exports the curve as an Unreal Engine curve table CSV, which can be imported as a `FloatCurve` asset.
+    ///
+    /// The curve is sampled at `resolution` uniformly spaced x-values over its domain, which make up the header row `---,x0,x1,...`.
+    /// The samples are written to a single row named after the curve, or `CurveKey` if it has no name.
+    /// A curve with a single knot is written as a single column, and an empty curve without any columns.
+    pub fn to_unreal_curve_csv(&self, resolution: u32) -> String {
+        let (min, max) = self.domain();
+        let xs: Vec<f32> = match self.knots.len() {
+            0 => vec![],
+            1 => vec![min],
+            _ => {
+                let resolution = resolution.max(2);
+                (0..resolution)
+                    .map(|i| min + (max - min) * i as f32 / (resolution - 1) as f32)
+                    .collect()
+            }
+        };
+
+        let name = self
+            .name
+            .as_deref()
+            .map(|name| name.replace([',', '\n', '"'], ""))
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| DEFAULT_ROW_NAME.to_string());
+
+        let mut csv = String::from("---");
+        for x in &xs {
+            write!(csv, ",{}", x).unwrap();
+        }
+        write!(csv, "\n{}", name).unwrap();
+        let mut cache = LookupCache::new();
+        for x in &xs {
+            write!(csv, ",{}", self.lookup_cached(*x, &mut cache)).unwrap();
+        }
+        csv.push('\n');
+        csv
+    }
+
+    /// Constructs a [LookupCurve] from an Unreal Engine curve table CSV, as written by [LookupCurve::to_unreal_curve_csv].
+    ///
+    /// Each value of the first curve row becomes a [KnotInterpolation::Linear] knot, and the name of the row is used as the curve name.
+    /// Any further rows are ignored.
+    pub fn from_unreal_curve_csv(csv: &str) -> Result<LookupCurve, ParseError> {
+        let mut lines = csv.lines().map(str::trim).filter(|line| !line.is_empty());
+
+        let mut header = lines.next().ok_or(ParseError::MissingHeader)?.split(',');
+        header.next().ok_or(ParseError::MissingHeader)?;
+        let xs = parse_values(header)?;
+        if xs
+            .windows(2)
+            .any(|w| w[1] - w[0] < LookupCurve::KNOT_X_EPSILON)
+        {
+            return Err(ParseError::NonIncreasingX);
+        }
+
+        let mut row = lines.next().ok_or(ParseError::MissingCurve)?.split(',');
+        let name = row.next().unwrap_or_default().trim();
+        let ys = parse_values(row)?;
+        if ys.len() != xs.len() {
+            return Err(ParseError::LengthMismatch {
+                expected: xs.len(),
+                found: ys.len(),
+            });
+        }
+
+        let knots = xs
+            .into_iter()
+            .zip(ys)
+            .map(|(x, y)| Knot::at(x, y).with_interpolation(KnotInterpolation::Linear))
+            .collect();
+        let curve = LookupCurve::new_unchecked(knots);
+        Ok(if name.is_empty() {
+            curve
+        } else {
+            curve.with_name(name)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreal_csv_roundtrip() {
+        let curve = LookupCurve::with_knots(vec![
+            Knot::at(0.0, 0.0)
+                .with_interpolation(KnotInterpolation::Cubic)
+                .with_slope(2.0),
+            Knot::at(0.5, 1.0).with_interpolation(KnotInterpolation::Cubic),
+            Knot::at(2.0, 0.25),
+        ])
+        .unwrap()
+        .with_name("Jump height");
+
+        let csv = curve.to_unreal_curve_csv(1024);
+        assert!(csv.starts_with("---,0,"));
+        assert!(csv.lines().nth(1).unwrap().starts_with("Jump height,0,"));
+
+        let imported = LookupCurve::from_unreal_curve_csv(&csv).unwrap();
+        assert_eq!(imported.name.as_deref(), Some("Jump height"));
+        assert_eq!(imported.knots().len(), 1024);
+        assert_eq!(imported.domain(), (0.0, 2.0));
+        assert!(imported.max_deviation_from(&curve, 4096) < 1e-3);
+
+        let single = LookupCurve::with_knots(vec![Knot::at(1.5, 2.0)]).unwrap();
+        let csv = single.to_unreal_curve_csv(1024);
+        assert_eq!(csv, "---,1.5\nCurveKey,2\n");
+        let imported = LookupCurve::from_unreal_curve_csv(&csv).unwrap();
+        assert_eq!(imported.knots().len(), 1);
+        assert_eq!(imported.knots()[0].position, single.knots()[0].position);
+
+        let empty = LookupCurve::default().with_name("Empty");
+        let csv = empty.to_unreal_curve_csv(1024);
+        assert_eq!(csv, "---\nEmpty\n");
+        let imported = LookupCurve::from_unreal_curve_csv(&csv).unwrap();
+        assert!(imported.knots().is_empty());
+        assert_eq!(imported.name.as_deref(), Some("Empty"));
+    }
+
+    #[test]
+    fn unreal_csv_errors() {
+        assert_eq!(
+            LookupCurve::from_unreal_curve_csv("").unwrap_err(),
+            ParseError::MissingHeader
+        );
+        assert_eq!(
+            LookupCurve::from_unreal_curve_csv("---,0,1").unwrap_err(),
+            ParseError::MissingCurve
+        );
+        assert_eq!(
+            LookupCurve::from_unreal_curve_csv("---,0,1\nCurveKey,0,x").unwrap_err(),
+            ParseError::InvalidNumber("x".to_string())
+        );
+        assert_eq!(
+            LookupCurve::from_unreal_curve_csv("---,0,1\nCurveKey,0").unwrap_err(),
+            ParseError::LengthMismatch {
+                expected: 2,
+                found: 1
+            }
+        );
+        assert_eq!(
+            LookupCurve::from_unreal_curve_csv("---,1,0\nCurveKey,0,1").unwrap_err(),
+            ParseError::NonIncreasingX
+        );
+    }
+}
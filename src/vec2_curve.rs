@@ -0,0 +1,117 @@
+use bevy_math::Vec2;
+
+use crate::{LookupCache, LookupCurve};
+
+/// Two-dimensional path defined by two [LookupCurve]s, one for each axis, sharing the same input `t`
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "bevy_asset", derive(bevy_asset::Asset))]
+pub struct Vec2LookupCurve {
+    /// Curve mapping `t` to the x-coordinate of the path
+    pub x_curve: LookupCurve,
+    /// Curve mapping `t` to the y-coordinate of the path
+    pub y_curve: LookupCurve,
+}
+
+/// Cache to speed up coherent samples, see [Vec2LookupCurve::sample_cached]
+#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Vec2LookupCache {
+    x: LookupCache,
+    y: LookupCache,
+}
+
+impl Vec2LookupCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Vec2LookupCurve {
+    pub fn new(x_curve: LookupCurve, y_curve: LookupCurve) -> Self {
+        Self { x_curve, y_curve }
+    }
+
+    /// Returns the range of `t` covered by the knots of either curve as `(min, max)`
+    pub fn domain(&self) -> (f32, f32) {
+        let (x_min, x_max) = self.x_curve.domain();
+        let (y_min, y_max) = self.y_curve.domain();
+        match (
+            self.x_curve.knots().is_empty(),
+            self.y_curve.knots().is_empty(),
+        ) {
+            (true, _) => (y_min, y_max),
+            (_, true) => (x_min, x_max),
+            _ => (x_min.min(y_min), x_max.max(y_max)),
+        }
+    }
+
+    /// Find the point on the path for given `t`
+    pub fn sample(&self, t: f32) -> Vec2 {
+        Vec2::new(self.x_curve.lookup(t), self.y_curve.lookup(t))
+    }
+
+    /// Find the point on the path for given `t`, with a [Vec2LookupCache]. Can speed up coherent samples, but might slow down random samples.
+    pub fn sample_cached(&self, t: f32, cache: &mut Vec2LookupCache) -> Vec2 {
+        Vec2::new(
+            self.x_curve.lookup_cached(t, &mut cache.x),
+            self.y_curve.lookup_cached(t, &mut cache.y),
+        )
+    }
+
+    #[cfg(feature = "ron")]
+    /// Loads a [Vec2LookupCurve] from a RON file
+    pub fn load_from_file(path: &str) -> Result<Self, crate::LookupCurveLoadError> {
+        let bytes = std::fs::read(path)?;
+        Ok(ron::de::from_bytes::<Vec2LookupCurve>(&bytes)?)
+    }
+
+    #[cfg(feature = "ron")]
+    /// Serializes the path and saves it as a RON file
+    pub fn save_to_file(&self, path: &str) -> Result<(), crate::LookupCurveSaveError> {
+        let s = crate::to_pretty_ron(self)?;
+        std::fs::write(path, s.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Knot, KnotInterpolation};
+
+    fn linear(from: Vec2, to: Vec2) -> LookupCurve {
+        LookupCurve::with_knots(vec![
+            Knot {
+                position: from,
+                interpolation: KnotInterpolation::Linear,
+                ..Default::default()
+            },
+            Knot {
+                position: to,
+                ..Default::default()
+            },
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn sample_combines_both_curves() {
+        let curve = Vec2LookupCurve::new(
+            linear(Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0)),
+            linear(Vec2::new(0.5, 1.0), Vec2::new(2.0, 0.0)),
+        );
+
+        assert_eq!(curve.domain(), (0.0, 2.0));
+        assert_eq!(curve.sample(0.0), Vec2::new(0.0, 1.0));
+        assert_eq!(curve.sample(2.0), Vec2::new(2.0, 0.0));
+
+        let mut cache = Vec2LookupCache::new();
+        for i in 0..=20 {
+            let t = i as f32 * 0.1;
+            assert_eq!(curve.sample_cached(t, &mut cache), curve.sample(t));
+        }
+    }
+}